@@ -100,7 +100,7 @@ class LanguageSwitcher {
       }
     } else if (savedLang && currentPath.startsWith('/en/')) {
       if (savedLang === 'th') {
-        const thaiPath = currentPath.replace('/en', '') || '/';
+        const thaiPath = this.getTranslationUrl('th') || currentPath.replace('/en', '') || '/';
         window.location.href = thaiPath;
         return;
       }
@@ -130,9 +130,21 @@ class LanguageSwitcher {
     });
   }
 
+  // Post pages list their translations as data-url-<lang> on the switcher
+  getTranslationUrl(lang) {
+    const container = document.querySelector('[data-language-switcher]');
+    return container?.getAttribute(`data-url-${lang}`) || null;
+  }
+
   switchLanguage(lang) {
     this.saveLanguagePreference(lang);
 
+    const translationUrl = this.getTranslationUrl(lang);
+    if (translationUrl) {
+      window.location.href = translationUrl;
+      return;
+    }
+
     const currentPath = window.location.pathname;
     let newPath;
 
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
 use toml;
 
@@ -92,7 +93,11 @@ pub struct Paths {
     pub system_dir: Option<String>,
     pub translations_dir: Option<String>,
     pub build_dir: Option<String>,
+    /// Data files for `site.data`, default `data`
+    pub data_dir: Option<String>,
     pub use_directory_structure: Option<bool>,
+    /// Post URL pattern, e.g. `/{section}/{year}/{month}/{slug}/`; a section's `index.yml` can override it
+    pub permalink: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]
@@ -146,6 +151,177 @@ pub struct SocialMeta {
     pub twitter_label1: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Feeds {
+    pub enabled: Option<bool>,
+    /// Any of `rss`, `atom`, `json`
+    pub formats: Option<Vec<String>>,
+    pub full_content: Option<bool>,
+    pub limit: Option<usize>,
+    pub sections: Option<bool>,
+    pub tags: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Taxonomies {
+    pub enabled: Option<bool>,
+    /// e.g. `["tags", "categories", "difficulty"]`; custom names read the frontmatter key of the same name
+    pub names: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Pagination {
+    /// Posts per listing page; a section's `index.yml` can override it with `paginate`, 0 disables
+    pub page_size: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Related {
+    pub enabled: Option<bool>,
+    /// Related posts per post, 0 disables
+    pub limit: Option<usize>,
+    /// Score per shared tag
+    pub tag_weight: Option<f64>,
+    /// Score for the same category
+    pub category_weight: Option<f64>,
+    /// Score per shared keyword
+    pub keyword_weight: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Toc {
+    pub enabled: Option<bool>,
+    /// Shallowest heading level listed, e.g. 2 for `<h2>`
+    pub min_level: Option<u8>,
+    /// Deepest heading level listed
+    pub max_level: Option<u8>,
+}
+
+/// Markdown extensions and rendering, from `[markdown]` or a section's `markdown:` in `index.yml`
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Markdown {
+    /// Raw HTML in markdown is passed through, default true
+    pub unsafe_html: Option<bool>,
+    /// `~~deleted~~`, default true
+    pub strikethrough: Option<bool>,
+    /// GitHub tables, default true
+    pub tables: Option<bool>,
+    /// `[^1]` footnotes, default true
+    pub footnotes: Option<bool>,
+    /// Bare URLs become links
+    pub autolink: Option<bool>,
+    /// `- [ ]` and `- [x]` task lists
+    pub tasklists: Option<bool>,
+    /// `Term` followed by `: details`
+    pub description_lists: Option<bool>,
+    /// `^superscript^`
+    pub superscript: Option<bool>,
+    /// `~subscript~`
+    pub subscript: Option<bool>,
+    /// `$inline$`, `$$display$$` and `` $`code`$ `` math
+    pub math: Option<bool>,
+    /// `> [!NOTE]` GitHub alerts
+    pub alerts: Option<bool>,
+    /// `[[page]]` and `[[page|title]]` links
+    pub wikilinks: Option<bool>,
+    /// `__underline__` instead of strong emphasis
+    pub underline: Option<bool>,
+    /// `||spoiler||`
+    pub spoilers: Option<bool>,
+    /// Curly quotes, dashes and ellipses
+    pub smart_punctuation: Option<bool>,
+    /// Every newline in a paragraph becomes `<br>`
+    pub hard_breaks: Option<bool>,
+    /// Images with a title render as `<figure>` with a `<figcaption>`
+    pub figure_with_caption: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct FrontmatterSchema {
+    /// Fields every post must set, default `["title"]`
+    pub required: Option<Vec<String>>,
+    /// Allowed values by field, e.g. `category = ["seo", "web-optimization"]`
+    pub values: Option<BTreeMap<String, Vec<String>>>,
+    /// Longer titles get a warning, default 60 characters
+    pub title_max_length: Option<usize>,
+    /// Longer descriptions get a warning, default 160 characters
+    pub description_max_length: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct Validation {
+    /// Set to false to skip filename and content rules
+    pub enabled: Option<bool>,
+    /// Fail the build when any rule reports an error
+    pub strict: Option<bool>,
+    pub allowed_image_formats: Option<Vec<String>>,
+    /// Characters not allowed in a post's filename slug
+    pub forbidden_chars: Option<Vec<char>>,
+    pub max_image_size_kb: Option<u64>,
+    pub max_image_width: Option<u32>,
+    pub max_image_height: Option<u32>,
+    pub banned_words: Option<Vec<String>>,
+    pub max_slug_length: Option<usize>,
+    /// `off`, `warn` or `error` for each rule
+    pub severity: Option<crate::shared::validation::Severities>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Params {
+    /// Expected types of custom front matter params, by name
+    pub schema: Option<BTreeMap<String, crate::shared::params::ParamSpec>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Git {
+    /// Take a post's `date_modified` from the last commit touching its file
+    pub last_modified: Option<bool>,
+    /// "Edit this page" link, `{path}` being the file's path in the repository,
+    /// e.g. `https://github.com/user/blog/edit/main/{path}`
+    pub edit_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Summary {
+    /// Characters in a summary for posts without a `<!--more-->` separator
+    pub length: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct Reading {
+    /// Reading speed per language code, e.g. `{ th = 200, en = 230 }`
+    pub words_per_minute: Option<BTreeMap<String, u32>>,
+}
+
+/// A writer, keyed by id in `[authors.<id>]` or `authors.yml`
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[allow(dead_code)]
+pub struct AuthorInfo {
+    pub name: String,
+    /// Name per language code, e.g. `{ en = "Boy Chawin" }`
+    pub names: Option<BTreeMap<String, String>>,
+    pub bio: Option<String>,
+    /// Bio per language code
+    pub bios: Option<BTreeMap<String, String>>,
+    pub avatar: Option<String>,
+    /// Personal website
+    pub url: Option<String>,
+    pub email: Option<String>,
+    /// Profile links keyed by network, e.g. `github`, `x`, `facebook`
+    pub social: Option<BTreeMap<String, String>>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Default)]
 #[allow(dead_code)]
 pub struct Locales {
@@ -171,6 +347,42 @@ pub struct AppConfig {
     pub seo: Option<Seo>,
     pub locales: Option<Locales>,
     pub social_meta: Option<SocialMeta>,
+    pub feeds: Option<Feeds>,
+    pub taxonomies: Option<Taxonomies>,
+    pub pagination: Option<Pagination>,
+    pub related: Option<Related>,
+    pub authors: Option<BTreeMap<String, AuthorInfo>>,
+    /// Old path to new path or URL, e.g. `"/old-post" = "/seo/new-post.html"`
+    pub redirects: Option<BTreeMap<String, String>>,
+    pub reading: Option<Reading>,
+    pub toc: Option<Toc>,
+    pub summary: Option<Summary>,
+    pub git: Option<Git>,
+    pub params: Option<Params>,
+    pub frontmatter: Option<FrontmatterSchema>,
+    pub validation: Option<Validation>,
+    pub markdown: Option<Markdown>,
+}
+
+impl AppConfig {
+    /// Words per minute for reading time in `language_code`
+    #[must_use]
+    pub fn reading_speed(&self, language_code: &str) -> u32 {
+        self.reading
+            .as_ref()
+            .and_then(|r| r.words_per_minute.as_ref())
+            .and_then(|wpm| wpm.get(language_code).copied())
+            .unwrap_or(crate::constants::content::READING_TIME_WPM)
+    }
+
+    /// Characters in an automatic post summary
+    #[must_use]
+    pub fn summary_length(&self) -> usize {
+        self.summary
+            .as_ref()
+            .and_then(|s| s.length)
+            .unwrap_or(crate::constants::content::DEFAULT_EXCERPT_LENGTH)
+    }
 }
 
 #[must_use]
@@ -190,8 +402,10 @@ pub fn read_config() -> AppConfig {
     match toml::from_str::<AppConfig>(&config_str) {
         Ok(config) => config,
         Err(e) => {
-            eprintln!("│  ⚠️  Warning: Failed to parse '{path}': {e}. Using default configuration.");
+            eprintln!(
+                "│  ⚠️  Warning: Failed to parse '{path}': {e}. Using default configuration."
+            );
             AppConfig::default()
         }
     }
-}
\ No newline at end of file
+}
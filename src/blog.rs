@@ -1,9 +1,18 @@
 use crate::app::AppConfig;
+use crate::constants::paths::DEFAULT_PERMALINK;
+use crate::generator::schedule::{post_status, BuildOptions, PostStatus};
+use crate::shared::bundle;
+use crate::shared::frontmatter;
+use crate::shared::markdown_to_html::options::options as markdown_options;
+use crate::shared::pages;
+use crate::shared::params::{self, Params};
+use crate::shared::url::localized_path;
 use crate::shared::utils::add_postfix_slash;
 use log::info;
 
 use super::posts::Post;
 use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 static MANIFEST_FILE: &str = "index.yml";
@@ -78,6 +87,17 @@ pub struct Manifest {
     date_published: Option<String>,
     #[serde(default)]
     category: Option<String>,
+    #[serde(default)]
+    paginate: Option<usize>,
+    /// Post URL pattern for this section, overriding `[paths] permalink`
+    #[serde(default)]
+    permalink: Option<String>,
+    /// Custom fields for templates, as `article.params`
+    #[serde(default)]
+    params: Params,
+    /// Markdown settings for this section's posts, over `[markdown]`
+    #[serde(default)]
+    markdown: Option<crate::app::Markdown>,
 }
 
 #[derive(Serialize)]
@@ -100,26 +120,80 @@ pub struct Article {
     pub date_modified: String,
     pub date_published: String,
     pub category: String,
+    pub paginate: Option<usize>,
+    /// Posts dated in the future, held back until their publish date
+    #[serde(skip)]
+    pub scheduled: Vec<Post>,
+    /// Posts whose `expiry_date` has passed
+    #[serde(skip)]
+    pub expired: Vec<Post>,
+    pub params: Params,
 }
 
 impl Article {
-    fn load(prefix: PathBuf, dir: &Path, app: &AppConfig) -> eyre::Result<Self> {
-    let manifest_content = crate::shared::fs::read_file_to_string(dir.join(MANIFEST_FILE))?;
-        let manifest: Manifest = serde_yaml::from_str(&manifest_content)?;
+    fn load(
+        prefix: PathBuf,
+        dir: &Path,
+        app: &AppConfig,
+        options: &BuildOptions,
+    ) -> eyre::Result<Self> {
+        let manifest = read_manifest(dir, app)?;
+
+        let permalink = manifest
+            .permalink
+            .as_deref()
+            .or_else(|| app.paths.as_ref().and_then(|p| p.permalink.as_deref()))
+            .unwrap_or(DEFAULT_PERMALINK);
+
+        // Bundle assets are published under the section language's root
+        let root = localized_path(&app.languages.default_language, &manifest.lang, "");
+        let markdown = markdown_options(app, manifest.markdown.as_ref());
+        let words_per_minute = app.reading_speed(&manifest.lang);
 
         let mut posts = Vec::new();
         for entry in std::fs::read_dir(dir)? {
             let path = entry?.path();
+            let file_type = path.metadata()?.file_type();
             let ext = path.extension().and_then(|e| e.to_str());
-            if path.metadata()?.file_type().is_file() && ext == Some(POSTS_EXT) {
-                posts.push(Post::open(&path, app, &prefix)?);
+            // Files marked `layout: page` are rendered with the other pages
+            if file_type.is_file() && ext == Some(POSTS_EXT) && !pages::is_page(&path)? {
+                posts.push(Post::open(
+                    &path,
+                    app,
+                    &prefix,
+                    permalink,
+                    &root,
+                    &markdown,
+                    words_per_minute,
+                )?);
+            } else if file_type.is_dir() {
+                if let Some(index) = bundle::index_file(&path) {
+                    posts.push(Post::open(
+                        &index,
+                        app,
+                        &prefix,
+                        permalink,
+                        &root,
+                        &markdown,
+                        words_per_minute,
+                    )?);
+                }
+            }
+        }
+
+        // Every post of a draft section is a draft
+        if manifest.draft {
+            for post in &mut posts {
+                post.draft = true;
             }
         }
 
+        let (mut posts, scheduled, expired) = partition_by_schedule(posts, options);
+
         let releases = if posts.is_empty() {
             Vec::new()
         } else {
-            process_posts(&mut posts)
+            process_posts(&mut posts, options)
         };
         Ok(Self {
             layout: manifest.layout,
@@ -139,6 +213,10 @@ impl Article {
             date_modified: manifest.date_modified.unwrap_or_default(),
             date_published: manifest.date_published.unwrap_or_default(),
             category: manifest.category.unwrap_or_default(),
+            paginate: manifest.paginate,
+            scheduled,
+            expired,
+            params: manifest.params,
         })
     }
 
@@ -167,9 +245,20 @@ impl Article {
     }
 }
 
-pub fn load(base: &Path, app: &AppConfig) -> eyre::Result<Vec<Article>> {
+/// Parse and validate a section's `index.yml`
+pub(crate) fn read_manifest(dir: &Path, app: &AppConfig) -> eyre::Result<Manifest> {
+    let path = dir.join(MANIFEST_FILE);
+    let manifest_content = crate::shared::fs::read_file_to_string(&path)?;
+    let manifest: Manifest = frontmatter::parse_yaml(&path, &manifest_content, 1)?;
+    params::validate(&manifest.params, app)
+        .map_err(|e| eyre::eyre!("Invalid params in {}: {e}", path.display()))?;
+    Ok(manifest)
+}
+
+pub fn load(base: &Path, app: &AppConfig, options: &BuildOptions) -> eyre::Result<Vec<Article>> {
     let mut articles = Vec::new();
-    load_recursive(base, base, &mut articles, app)?;
+    load_recursive(base, base, &mut articles, app, options)?;
+    check_permalink_collisions(&articles)?;
     Ok(articles)
 }
 
@@ -178,13 +267,14 @@ fn load_recursive(
     current: &Path,
     articles: &mut Vec<Article>,
     app: &AppConfig,
+    options: &BuildOptions,
 ) -> eyre::Result<()> {
     for entry in std::fs::read_dir(current)? {
         let path = entry?.path();
         let file_type = path.metadata()?.file_type();
 
         if file_type.is_dir() {
-            load_recursive(base, &path, articles, app)?;
+            load_recursive(base, &path, articles, app, options)?;
         } else if file_type.is_file() {
             let file_name = path.file_name().and_then(|n| n.to_str());
             if let (Some(file_name), Some(parent)) = (file_name, path.parent()) {
@@ -211,7 +301,7 @@ fn load_recursive(
                         }
                     }
 
-                    articles.push(Article::load(prefix, parent, app)?);
+                    articles.push(Article::load(prefix, parent, app, options)?);
                 }
             }
         }
@@ -219,7 +309,56 @@ fn load_recursive(
     Ok(())
 }
 
-fn process_posts(posts: &mut Vec<Post>) -> Vec<Post> {
+/// Two posts of the same language must not resolve to the same URL
+fn check_permalink_collisions(articles: &[Article]) -> eyre::Result<()> {
+    let mut seen: HashMap<(&str, &str), &str> = HashMap::new();
+    for article in articles {
+        for post in article
+            .posts
+            .iter()
+            .chain(&article.scheduled)
+            .chain(&article.expired)
+        {
+            if let Some(other) = seen.insert((article.lang(), &post.url), &post.title) {
+                return Err(eyre::eyre!(
+                    "Permalink collision: \"{other}\" and \"{}\" both resolve to {} ({}); set a different `slug:` or permalink pattern",
+                    post.title,
+                    post.url,
+                    article.lang()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Split posts into those published in this build, those scheduled for later and expired ones
+fn partition_by_schedule(
+    posts: Vec<Post>,
+    options: &BuildOptions,
+) -> (Vec<Post>, Vec<Post>, Vec<Post>) {
+    let mut published = Vec::with_capacity(posts.len());
+    let mut scheduled = Vec::new();
+    let mut expired = Vec::new();
+
+    for post in posts {
+        match post_status(&post, options) {
+            PostStatus::Published => published.push(post),
+            PostStatus::Scheduled => {
+                info!("│  ⏳ Scheduled: {} ({})\n", post.title, post.url);
+                scheduled.push(post);
+            }
+            PostStatus::Expired => {
+                info!("│  ⌛ Expired: {} ({})\n", post.title, post.url);
+                expired.push(post);
+            }
+        }
+    }
+
+    (published, scheduled, expired)
+}
+
+fn process_posts(posts: &mut Vec<Post>, options: &BuildOptions) -> Vec<Post> {
     posts.sort_by_key(|post| {
         format!(
             "{}-{:02}-{:02}-{}",
@@ -246,6 +385,13 @@ fn process_posts(posts: &mut Vec<Post>) -> Vec<Post> {
         }
     }
 
+    if options.drafts {
+        for post in posts.iter().filter(|post| post.draft) {
+            info!("│  📝 Draft preview: {} ({})\n", post.title, post.url);
+        }
+        return std::mem::take(posts);
+    }
+
     let (released_posts, unreleased_posts): (Vec<Post>, Vec<Post>) =
         posts.drain(..).partition(|post| !post.draft);
 
@@ -254,4 +400,4 @@ fn process_posts(posts: &mut Vec<Post>) -> Vec<Post> {
     }
 
     released_posts
-}
\ No newline at end of file
+}
@@ -1,6 +1,9 @@
+use eyre::eyre;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::blog::Article;
+
 pub struct AssetManager {
     out_directory: PathBuf,
 }
@@ -22,6 +25,35 @@ impl AssetManager {
         Ok(())
     }
 
+    /// Copy the files of every page bundle to its post's directory URL
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a bundle file would overwrite a page or another file in the build
+    pub fn copy_bundle_assets(&self, articles: &[Article]) -> eyre::Result<()> {
+        for post in articles.iter().flat_map(Article::posts) {
+            let Some(bundle) = &post.bundle else {
+                continue;
+            };
+            let dest_dir = self.out_directory.join(bundle.url.trim_start_matches('/'));
+            for asset in bundle.assets()? {
+                let dest = dest_dir.join(&asset);
+                if dest.exists() {
+                    return Err(eyre!(
+                        "Bundle file {} would overwrite {} in the build",
+                        bundle.dir.join(&asset).display(),
+                        dest.display()
+                    ));
+                }
+                if let Some(parent) = dest.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::copy(bundle.dir.join(&asset), &dest)?;
+            }
+        }
+        Ok(())
+    }
+
     fn copy_public_directory(&self) -> eyre::Result<()> {
         let public_dir = Path::new("public");
         if public_dir.exists() {
@@ -84,4 +116,4 @@ impl AssetManager {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
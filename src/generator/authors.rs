@@ -0,0 +1,246 @@
+use log::warn;
+use serde_derive::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::app::{AppConfig, AuthorInfo};
+use crate::blog::Article;
+use crate::constants::files::AUTHORS;
+use crate::posts::Post;
+
+use super::site_builder::page_output_path;
+use super::sitemap::output_path_to_url;
+
+/// An author as seen by templates, with names and URLs for one language
+#[derive(Debug, Clone, Serialize)]
+pub struct Author<'a> {
+    pub id: String,
+    pub name: String,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+    /// Profile page (`/authors/<id>/`)
+    pub url: String,
+    /// Author index (`/authors/`)
+    pub authors_url: String,
+    /// Personal website
+    pub website: Option<String>,
+    pub email: Option<String>,
+    pub social: BTreeMap<String, String>,
+    /// Website and social links, for `sameAs` in JSON-LD
+    pub same_as: Vec<String>,
+    /// Number of posts by this author
+    pub post_count: usize,
+    /// Posts by this author, newest first; empty for the authors of a post
+    #[serde(skip)]
+    pub posts: Vec<&'a Post>,
+}
+
+/// Authors from `[authors]` in `app.toml` and `authors.yml`, keyed by id
+#[derive(Debug, Default)]
+pub struct AuthorRegistry {
+    authors: BTreeMap<String, AuthorInfo>,
+}
+
+impl AuthorRegistry {
+    #[must_use]
+    pub fn new(authors: BTreeMap<String, AuthorInfo>) -> Self {
+        Self { authors }
+    }
+
+    /// Read `[authors]` from the config, then `authors.yml`; entries in the file win
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `authors.yml` exists but cannot be read or parsed
+    pub fn load(app: &AppConfig) -> eyre::Result<Self> {
+        let mut authors = app.authors.clone().unwrap_or_default();
+
+        if Path::new(AUTHORS).exists() {
+            let yaml = crate::shared::fs::read_file_to_string(AUTHORS)?;
+            let from_file: BTreeMap<String, AuthorInfo> =
+                serde_yaml::from_str(&yaml).map_err(|e| eyre::eyre!("Invalid {AUTHORS}: {e}"))?;
+            authors.extend(from_file);
+        }
+
+        Ok(Self::new(authors))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.authors.is_empty()
+    }
+
+    /// Id of the author referenced as `value`, by id or by exact (case-insensitive) name
+    #[must_use]
+    pub fn find(&self, value: &str) -> Option<&str> {
+        let value = value.trim();
+        if let Some((id, _)) = self.authors.get_key_value(value) {
+            return Some(id);
+        }
+        self.authors
+            .iter()
+            .find(|(_, info)| {
+                info.name.eq_ignore_ascii_case(value)
+                    || info
+                        .names
+                        .iter()
+                        .flat_map(BTreeMap::values)
+                        .any(|name| name.eq_ignore_ascii_case(value))
+            })
+            .map(|(id, _)| id.as_str())
+    }
+
+    /// Replace the free-text author fields of every post with registry data
+    pub fn resolve_articles(&self, articles: &mut [Article], app: &AppConfig) {
+        if self.is_empty() {
+            return;
+        }
+        for article in articles {
+            let lang = article.lang().clone();
+            for post in article
+                .posts
+                .iter_mut()
+                .chain(article.scheduled.iter_mut())
+                .chain(article.expired.iter_mut())
+            {
+                self.resolve_post(post, app, &lang);
+            }
+        }
+    }
+
+    /// Resolve `author: <id>` or `authors: [a, b]` into registry ids, and fill
+    /// `author`, `author_url` and `author_email` from the first author, in the
+    /// post's `language_code`, so feeds and older templates keep working.
+    /// Unknown `author` values stay free text.
+    pub fn resolve_post(&self, post: &mut Post, app: &AppConfig, language_code: &str) {
+        let explicit = !post.authors.is_empty();
+        let references = if explicit {
+            post.authors.clone()
+        } else {
+            vec![post.author.clone()]
+        };
+
+        let mut ids: Vec<String> = Vec::new();
+        for reference in &references {
+            match self.find(reference) {
+                Some(id) if !ids.iter().any(|i| i == id) => ids.push(id.to_string()),
+                Some(_) => {}
+                None if explicit => warn!(
+                    "│  ⚠️ Unknown author '{reference}' in {}, add it to {AUTHORS}",
+                    post.url
+                ),
+                None => {}
+            }
+        }
+
+        if let Some(first) = ids.first().and_then(|id| self.authors.get(id)) {
+            post.author = ids
+                .iter()
+                .filter_map(|id| self.authors.get(id))
+                .map(|info| localized_name(info, language_code))
+                .collect::<Vec<_>>()
+                .join(", ");
+            if post.author_url.is_empty() {
+                post.author_url = format!(
+                    "{}{}",
+                    app.app_info.app_domain,
+                    profile_url(app, language_code, &ids[0])
+                );
+            }
+            if post.author_email.is_empty() {
+                post.author_email = first.email.clone().unwrap_or_default();
+            }
+        }
+        post.authors = ids;
+    }
+
+    /// Authors of `post` for a page in `language_code`
+    #[must_use]
+    pub fn for_post(
+        &self,
+        post: &Post,
+        app: &AppConfig,
+        language_code: &str,
+    ) -> Vec<Author<'static>> {
+        post.authors
+            .iter()
+            .filter_map(|id| self.author(id, app, language_code))
+            .collect()
+    }
+
+    /// Every author with their posts in `language_code`, ordered by id
+    #[must_use]
+    pub fn build_all<'a>(
+        &self,
+        app: &AppConfig,
+        articles: &[&'a Article],
+        language_code: &str,
+    ) -> Vec<Author<'a>> {
+        let mut posts: Vec<&'a Post> = articles.iter().flat_map(|a| a.posts()).collect();
+        posts.sort_by_key(|p| std::cmp::Reverse((p.year, p.month, p.day)));
+
+        self.authors
+            .keys()
+            .filter_map(|id| {
+                let mut author = self.author(id, app, language_code)?;
+                author.posts = posts
+                    .iter()
+                    .filter(|p| p.authors.iter().any(|a| a == id))
+                    .copied()
+                    .collect();
+                author.post_count = author.posts.len();
+                Some(author)
+            })
+            .collect()
+    }
+
+    fn author<'a>(&self, id: &str, app: &AppConfig, language_code: &str) -> Option<Author<'a>> {
+        let info = self.authors.get(id)?;
+        let social = info.social.clone().unwrap_or_default();
+        let same_as = info
+            .url
+            .iter()
+            .chain(social.values())
+            .filter(|link| !link.is_empty())
+            .cloned()
+            .collect();
+
+        Some(Author {
+            id: id.to_string(),
+            name: localized_name(info, language_code),
+            bio: info
+                .bios
+                .as_ref()
+                .and_then(|bios| bios.get(language_code))
+                .or(info.bio.as_ref())
+                .cloned(),
+            avatar: info.avatar.clone(),
+            url: profile_url(app, language_code, id),
+            authors_url: output_path_to_url(&page_output_path(app, language_code, "authors")),
+            website: info.url.clone(),
+            email: info.email.clone(),
+            social,
+            same_as,
+            post_count: 0,
+            posts: Vec::new(),
+        })
+    }
+}
+
+fn localized_name(info: &AuthorInfo, language_code: &str) -> String {
+    info.names
+        .as_ref()
+        .and_then(|names| names.get(language_code))
+        .unwrap_or(&info.name)
+        .clone()
+}
+
+/// URL of an author's profile page
+#[must_use]
+pub fn profile_url(app: &AppConfig, language_code: &str, id: &str) -> String {
+    output_path_to_url(&page_output_path(
+        app,
+        language_code,
+        &format!("authors/{id}"),
+    ))
+}
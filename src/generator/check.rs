@@ -0,0 +1,536 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::app::AppConfig;
+use crate::blog;
+use crate::constants::files::BUNDLE_INDEX;
+use crate::constants::paths::PUBLIC_DIR;
+use crate::constants::seo::{DESCRIPTION_MAX_LENGTH, TITLE_MAX_LENGTH};
+use crate::error::GenWebBlogError;
+use crate::generator::schedule::parse_date;
+use crate::posts;
+use crate::shared::bundle;
+use crate::shared::frontmatter::{self, Format, Frontmatter};
+use crate::shared::pages;
+use crate::shared::read_metadata_yml::GenericYmlInfo;
+use crate::validation::{FileValidator, ValidationResult};
+
+const MANIFEST_FILE: &str = "index.yml";
+
+const DATE_FIELDS: &[&str] = &["date", "date_published", "date_modified", "expiry_date"];
+
+/// Dates a post cannot be loaded without when they are invalid
+const PUBLISH_DATE_FIELDS: &[&str] = &["date", "date_published"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+    /// The file cannot be loaded at all, so the site cannot be built
+    Fatal,
+}
+
+/// One problem in one content file
+#[derive(Debug, Clone)]
+pub struct Problem {
+    pub file: PathBuf,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Problem {
+    fn new(file: &Path, line: Option<usize>, severity: Severity, message: String) -> Self {
+        Self {
+            file: file.to_path_buf(),
+            line,
+            column: None,
+            severity,
+            message,
+        }
+    }
+
+    /// A load error, located when it comes from the front matter parser
+    fn from_report(file: &Path, report: &eyre::Report) -> Self {
+        match report.downcast_ref::<GenWebBlogError>() {
+            Some(GenWebBlogError::Frontmatter {
+                file,
+                line,
+                column,
+                message,
+            }) => Self {
+                file: file.clone(),
+                line: Some(*line),
+                column: Some(*column),
+                severity: Severity::Fatal,
+                message: message.clone(),
+            },
+            _ => Self::new(file, None, Severity::Fatal, report.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+            if let Some(column) = self.column {
+                write!(f, ":{column}")?;
+            }
+        }
+        let label = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error | Severity::Fatal => "error",
+        };
+        write!(f, ": {label}: {}", self.message)
+    }
+}
+
+/// Every problem found in the content directory
+#[derive(Debug, Default)]
+pub struct CheckReport {
+    pub files: usize,
+    pub problems: Vec<Problem>,
+}
+
+impl CheckReport {
+    fn count(&self, severities: &[Severity]) -> usize {
+        self.problems
+            .iter()
+            .filter(|p| severities.contains(&p.severity))
+            .count()
+    }
+
+    #[must_use]
+    pub fn errors(&self) -> usize {
+        self.count(&[Severity::Error, Severity::Fatal])
+    }
+
+    #[must_use]
+    pub fn warnings(&self) -> usize {
+        self.count(&[Severity::Warning])
+    }
+
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.errors() > 0
+    }
+
+    /// Some file cannot be loaded
+    #[must_use]
+    pub fn has_fatal(&self) -> bool {
+        self.count(&[Severity::Fatal]) > 0
+    }
+
+    pub fn print(&self) {
+        for problem in &self.problems {
+            let icon = match problem.severity {
+                Severity::Warning => "⚠️ ",
+                Severity::Error | Severity::Fatal => "❌",
+            };
+            println!("│    {icon} {problem}");
+        }
+        println!(
+            "│  📋 Checked {} content files: {} errors, {} warnings",
+            self.files,
+            self.errors(),
+            self.warnings()
+        );
+    }
+
+    fn push(&mut self, problem: Problem) {
+        self.problems.push(problem);
+    }
+}
+
+/// Parse every section, post and page under `contents_dir` and collect all problems
+/// instead of stopping at the first one
+#[must_use]
+pub fn check_contents(
+    app: &AppConfig,
+    contents_dir: &Path,
+    validator: &FileValidator,
+) -> CheckReport {
+    let mut report = CheckReport::default();
+    let checker = Checker { app, validator };
+    checker.check_dir(contents_dir, &mut report);
+    report
+        .problems
+        .sort_by(|a, b| (&a.file, a.line.unwrap_or(0)).cmp(&(&b.file, b.line.unwrap_or(0))));
+    report
+}
+
+struct Checker<'a> {
+    app: &'a AppConfig,
+    validator: &'a FileValidator,
+}
+
+impl Checker<'_> {
+    fn check_dir(&self, dir: &Path, report: &mut CheckReport) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            report.push(Problem::new(
+                dir,
+                None,
+                Severity::Fatal,
+                "cannot read directory".to_string(),
+            ));
+            return;
+        };
+        let mut paths: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+        paths.sort();
+
+        let is_section = dir.join(MANIFEST_FILE).is_file();
+        for path in paths {
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            if name.starts_with('.') {
+                continue;
+            }
+            let extension = path.extension().and_then(|e| e.to_str());
+            if path.is_dir() {
+                match bundle::index_file(&path) {
+                    Some(index) if is_section => self.check_post(&index, report),
+                    _ => self.check_dir(&path, report),
+                }
+            } else if name == MANIFEST_FILE {
+                self.check_section(dir, &path, report);
+            } else if extension == Some("yml") {
+                self.check_page(&path, report);
+            // Broken front matter makes it a post, whose check reports the error
+            } else if extension == Some("md") && pages::is_page(&path).unwrap_or(false) {
+                self.check_markdown_page(&path, report);
+            } else if extension == Some("md") && is_section && name != BUNDLE_INDEX {
+                self.check_post(&path, report);
+            }
+        }
+    }
+
+    fn check_section(&self, dir: &Path, path: &Path, report: &mut CheckReport) {
+        report.files += 1;
+        if let Err(e) = blog::read_manifest(dir, self.app) {
+            report.push(Problem::from_report(path, &e));
+            return;
+        }
+        if let Some(text) = read(path, report) {
+            let fields = yaml_fields(&text);
+            self.check_fields(path, &fields, &yaml_block(&text), None, false, report);
+        }
+    }
+
+    fn check_page(&self, path: &Path, report: &mut CheckReport) {
+        report.files += 1;
+        let Some(text) = read(path, report) else {
+            return;
+        };
+        let page: GenericYmlInfo = match frontmatter::parse_yaml(path, &text, 1) {
+            Ok(page) => page,
+            Err(e) => {
+                report.push(Problem::from_report(path, &e.into()));
+                return;
+            }
+        };
+        require_title_and_description(path, &page, report);
+        let fields = yaml_fields(&text);
+        self.check_fields(path, &fields, &yaml_block(&text), None, false, report);
+    }
+
+    fn check_markdown_page(&self, path: &Path, report: &mut CheckReport) {
+        report.files += 1;
+        report_validation(path, self.validator.validate_page(path), report);
+
+        let Some(text) = read(path, report) else {
+            return;
+        };
+        let page: GenericYmlInfo = match frontmatter::parse(path, &text) {
+            Ok((page, _)) => page,
+            Err(e) => {
+                report.push(Problem::from_report(path, &e.into()));
+                return;
+            }
+        };
+        require_title_and_description(path, &page, report);
+        let parsed = frontmatter::split(path, &text).and_then(|block| {
+            block
+                .map(|block| Ok((block, block.deserialize(path)?)))
+                .transpose()
+        });
+        if let Ok(Some((block, fields))) = parsed {
+            self.check_fields(path, &fields, &block, None, false, report);
+        }
+    }
+
+    fn check_post(&self, path: &Path, report: &mut CheckReport) {
+        report.files += 1;
+        report_validation(path, self.validator.validate_file(path), report);
+
+        let Some(text) = read(path, report) else {
+            return;
+        };
+        let parsed = frontmatter::split(path, &text).and_then(|block| {
+            block
+                .map(|block| Ok((block, block.deserialize(path)?)))
+                .transpose()
+        });
+        let (block, fields): (Frontmatter, BTreeMap<String, serde_yaml::Value>) = match parsed {
+            Ok(Some(parsed)) => parsed,
+            Ok(None) => {
+                // Nothing to locate; loading the post tells what is missing
+                if let Err(e) = posts::check(path, self.app) {
+                    report.push(Problem::from_report(path, &e));
+                }
+                return;
+            }
+            Err(e) => {
+                report.push(Problem::from_report(path, &e.into()));
+                return;
+            }
+        };
+
+        let schema = self.app.frontmatter.as_ref();
+        let default_required = ["title".to_string()];
+        let required = schema
+            .and_then(|s| s.required.as_deref())
+            .unwrap_or(&default_required);
+        for field in required {
+            if fields.get(field).is_none_or(is_blank) {
+                report.push(Problem::new(
+                    path,
+                    Some(1),
+                    Severity::Error,
+                    format!("`{field}` is required"),
+                ));
+            }
+        }
+
+        let first = report.problems.len();
+        self.check_fields(
+            path,
+            &fields,
+            &block,
+            bundle::bundle_dir(path),
+            true,
+            report,
+        );
+        // A bad publish date is already reported above, with its line
+        let located = report.problems[first..]
+            .iter()
+            .any(|p| p.severity == Severity::Fatal);
+
+        match posts::check(path, self.app) {
+            Ok(warnings) => {
+                for warning in warnings {
+                    report.push(Problem::new(path, None, Severity::Warning, warning));
+                }
+            }
+            Err(_) if located => {}
+            Err(e) => report.push(Problem::from_report(path, &e)),
+        }
+    }
+
+    /// Checks shared by posts, sections and pages: allowed values, dates, image and lengths
+    fn check_fields(
+        &self,
+        path: &Path,
+        fields: &BTreeMap<String, serde_yaml::Value>,
+        block: &Frontmatter,
+        bundle_dir: Option<&Path>,
+        post: bool,
+        report: &mut CheckReport,
+    ) {
+        let schema = self.app.frontmatter.as_ref();
+        let line = |key: &str| block.line_of(key);
+
+        if let Some(values) = schema.and_then(|s| s.values.as_ref()) {
+            for (field, allowed) in values {
+                let Some(value) = fields.get(field) else {
+                    continue;
+                };
+                for item in strings(value) {
+                    if !allowed.iter().any(|a| a == item) {
+                        report.push(Problem::new(
+                            path,
+                            line(field),
+                            Severity::Error,
+                            format!("`{field}` \"{item}\" is not one of: {}", allowed.join(", ")),
+                        ));
+                    }
+                }
+            }
+        }
+
+        for field in DATE_FIELDS {
+            if let Some(value) = fields.get(*field).and_then(serde_yaml::Value::as_str) {
+                if parse_date(value).is_none() {
+                    let severity = if post && PUBLISH_DATE_FIELDS.contains(field) {
+                        Severity::Fatal
+                    } else {
+                        Severity::Error
+                    };
+                    report.push(Problem::new(
+                        path,
+                        line(field),
+                        severity,
+                        format!(
+                            "`{field}` \"{value}\" is not a date: use RFC 3339 such as \
+                             2025-07-03T09:00:00+07:00, or YYYY-MM-DD"
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let Some(image) = fields.get("image").and_then(serde_yaml::Value::as_str) {
+            if let Some(looked_at) = missing_image(self.app, image, bundle_dir) {
+                report.push(Problem::new(
+                    path,
+                    line("image"),
+                    Severity::Error,
+                    format!("image \"{image}\" not found at {}", looked_at.display()),
+                ));
+            }
+        }
+
+        let limits = [
+            (
+                "title",
+                schema
+                    .and_then(|s| s.title_max_length)
+                    .unwrap_or(TITLE_MAX_LENGTH),
+            ),
+            (
+                "description",
+                schema
+                    .and_then(|s| s.description_max_length)
+                    .unwrap_or(DESCRIPTION_MAX_LENGTH),
+            ),
+        ];
+        for (field, max) in limits {
+            let Some(value) = fields.get(field).and_then(serde_yaml::Value::as_str) else {
+                continue;
+            };
+            let length = value.chars().count();
+            if length > max {
+                report.push(Problem::new(
+                    path,
+                    line(field),
+                    Severity::Warning,
+                    format!(
+                        "`{field}` is {length} characters, longer than {max}; \
+                         it may be truncated in search results"
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Problems found by the `[validation]` rules
+fn report_validation(
+    path: &Path,
+    result: eyre::Result<ValidationResult>,
+    report: &mut CheckReport,
+) {
+    match result {
+        Ok(result) => {
+            for error in result.errors {
+                report.push(Problem::new(
+                    path,
+                    error.line,
+                    Severity::Error,
+                    error.message,
+                ));
+            }
+            for warning in result.warnings {
+                report.push(Problem::new(
+                    path,
+                    warning.line,
+                    Severity::Warning,
+                    warning.message,
+                ));
+            }
+        }
+        Err(e) => report.push(Problem::new(path, None, Severity::Error, e.to_string())),
+    }
+}
+
+fn require_title_and_description(path: &Path, page: &GenericYmlInfo, report: &mut CheckReport) {
+    for (field, value) in [("title", &page.title), ("description", &page.description)] {
+        if value.trim().is_empty() {
+            report.push(Problem::new(
+                path,
+                Some(1),
+                Severity::Error,
+                format!("`{field}` is required"),
+            ));
+        }
+    }
+}
+
+fn read(path: &Path, report: &mut CheckReport) -> Option<String> {
+    match crate::shared::fs::read_file_to_string(path) {
+        Ok(text) => Some(text),
+        Err(e) => {
+            report.push(Problem::new(path, None, Severity::Fatal, e.to_string()));
+            None
+        }
+    }
+}
+
+fn yaml_fields(text: &str) -> BTreeMap<String, serde_yaml::Value> {
+    serde_yaml::from_str(text).unwrap_or_default()
+}
+
+/// A whole `.yml` file seen as a front matter block, for line lookups
+fn yaml_block(text: &str) -> Frontmatter<'_> {
+    Frontmatter {
+        format: Format::Yaml,
+        raw: text,
+        first_line: 1,
+        body: "",
+    }
+}
+
+fn is_blank(value: &serde_yaml::Value) -> bool {
+    match value {
+        serde_yaml::Value::Null => true,
+        serde_yaml::Value::String(s) => s.trim().is_empty(),
+        serde_yaml::Value::Sequence(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+/// The string, or the strings of a list
+fn strings(value: &serde_yaml::Value) -> Vec<&str> {
+    match value {
+        serde_yaml::Value::String(s) => vec![s.as_str()],
+        serde_yaml::Value::Sequence(items) => {
+            items.iter().filter_map(serde_yaml::Value::as_str).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Where a local `image` was expected, when it is not there
+fn missing_image(app: &AppConfig, image: &str, bundle_dir: Option<&Path>) -> Option<PathBuf> {
+    let image = image.trim();
+    if image.is_empty() || image.contains("://") || image.starts_with("//") {
+        return None;
+    }
+    let expected = match bundle_dir {
+        Some(dir) if !image.starts_with('/') => dir.join(image),
+        _ => {
+            let public_dir = app
+                .paths
+                .as_ref()
+                .and_then(|p| p.public_dir.as_deref())
+                .unwrap_or(PUBLIC_DIR);
+            Path::new(public_dir).join(image.trim_start_matches('/'))
+        }
+    };
+    (!expected.exists()).then_some(expected)
+}
@@ -4,9 +4,11 @@ use std::collections::HashSet;
 
 use crate::app::AppConfig;
 use crate::blog::Article;
-use crate::posts::Post;
+use crate::constants::seo::{DESCRIPTION_MAX_LENGTH, TITLE_MAX_LENGTH};
 use crate::shared::read_metadata_yml::GenericYmlInfo;
 
+use super::sitemap::SitemapBuilder;
+
 pub struct ContentParser<'a> {
     app: &'a AppConfig,
 }
@@ -46,16 +48,6 @@ impl<'a> ContentParser<'a> {
             .collect()
     }
 
-    #[allow(dead_code)]
-    #[must_use]
-    pub fn parse_posts_for_category(&self, posts: &[Post], category: &str) -> Vec<Post> {
-        posts
-            .iter()
-            .filter(|post| post.category.as_deref() == Some(category))
-            .cloned()
-            .collect()
-    }
-
     #[allow(dead_code)]
     #[must_use]
     pub fn extract_keywords_from_yml(&self, yml_info: &GenericYmlInfo) -> String {
@@ -89,12 +81,14 @@ impl<'a> ContentParser<'a> {
             return Err("Page name is required".to_string());
         }
 
-        if yml_info.title.len() > 60 {
-            warn!("⚠️ Warning: Title is longer than 60 characters ({}), may be truncated in search results", yml_info.title.len());
+        let title_length = yml_info.title.chars().count();
+        if title_length > TITLE_MAX_LENGTH {
+            warn!("⚠️ Warning: Title is longer than {TITLE_MAX_LENGTH} characters ({title_length}), may be truncated in search results");
         }
 
-        if yml_info.description.len() > 160 {
-            warn!("⚠️ Warning: Description is longer than 160 characters ({}), may be truncated in search results", yml_info.description.len());
+        let description_length = yml_info.description.chars().count();
+        if description_length > DESCRIPTION_MAX_LENGTH {
+            warn!("⚠️ Warning: Description is longer than {DESCRIPTION_MAX_LENGTH} characters ({description_length}), may be truncated in search results");
         }
 
         if yml_info.page_name.contains(' ') || yml_info.page_name.contains('_') {
@@ -164,63 +158,6 @@ impl<'a> ContentParser<'a> {
         Ok(())
     }
 
-    #[must_use]
-    pub fn create_sitemap_entries(&self, articles: &[Article], base_url: &str) -> Vec<Value> {
-        let mut entries = Vec::new();
-
-        for lang_code in &self.app.languages.installed_languages {
-            let filtered_articles = self.filter_articles_by_language(articles, lang_code);
-
-            for article in filtered_articles {
-                let relative_url = self.generate_article_url(article, lang_code);
-                let full_url = if relative_url == "/" {
-                    base_url.to_string()
-                } else {
-                    format!("{base_url}{relative_url}")
-                };
-
-                entries.push(json!({
-                    "url": full_url,
-                    "lastmod": article.date_published(),
-                    "changefreq": "weekly",
-                    "priority": "0.8",
-                    "lang": lang_code
-                }));
-            }
-        }
-
-        entries
-    }
-
-    #[must_use]
-    pub fn create_sitemap_entries_for_language(
-        &self,
-        articles: &[&Article],
-        base_url: &str,
-        language_code: &str,
-    ) -> Vec<Value> {
-        let mut entries = Vec::new();
-
-        for article in articles {
-            let relative_url = self.generate_article_url(article, language_code);
-            let full_url = if relative_url == "/" {
-                base_url.to_string()
-            } else {
-                format!("{base_url}{relative_url}")
-            };
-
-            entries.push(json!({
-                "url": full_url,
-                "lastmod": article.date_published(),
-                "changefreq": "weekly",
-                "priority": "0.8",
-                "lang": language_code
-            }));
-        }
-
-        entries
-    }
-
     #[must_use]
     pub fn generate_robots_txt(&self, base_url: &str) -> String {
         use std::fmt::Write;
@@ -230,15 +167,13 @@ impl<'a> ContentParser<'a> {
         robots_content.push_str("Allow: /\n");
         robots_content.push('\n');
 
+        let _ = writeln!(robots_content, "Sitemap: {base_url}/sitemap.xml");
         for lang_code in &self.app.languages.installed_languages {
-            if lang_code == &self.app.languages.default_language {
-                let _ = writeln!(robots_content, "Sitemap: {base_url}/sitemap.xml");
-            } else {
-                let _ = writeln!(
-                    robots_content,
-                    "Sitemap: {base_url}/{lang_code}/sitemap.xml"
-                );
-            }
+            let _ = writeln!(
+                robots_content,
+                "Sitemap: {base_url}/{}",
+                SitemapBuilder::sitemap_path_for_language(self.app, lang_code)
+            );
         }
 
         robots_content
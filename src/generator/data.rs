@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use eyre::{eyre, WrapErr};
+use serde_json::{Map, Value};
+
+use crate::app::AppConfig;
+use crate::constants::paths::DATA_DIR;
+use crate::shared::frontmatter;
+
+/// Files under `data/`, exposed to every template as `site.data`.
+///
+/// `data/faq.yml` becomes `site.data.faq` and `data/team/members.json` becomes
+/// `site.data.team.members`. Top-level folders named after an installed language
+/// (`data/en/faq.yml`) are merged over the shared tree for that language only.
+#[derive(Debug, Default)]
+pub struct SiteData {
+    shared: Value,
+    languages: HashMap<String, Value>,
+}
+
+impl SiteData {
+    /// Read the data directory (`[paths] data_dir`, default `data/`); missing is fine
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the file when a data file cannot be read or parsed
+    pub fn load(app: &AppConfig) -> eyre::Result<Self> {
+        let dir = app
+            .paths
+            .as_ref()
+            .and_then(|p| p.data_dir.as_deref())
+            .unwrap_or(DATA_DIR);
+        Self::load_from(Path::new(dir), &app.languages.installed_languages)
+    }
+
+    /// Read `dir`, treating its top-level folders in `languages` as per-language overrides
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the file when a data file cannot be read or parsed
+    pub fn load_from(dir: &Path, languages: &[String]) -> eyre::Result<Self> {
+        let mut data = Self {
+            shared: Value::Object(Map::new()),
+            languages: HashMap::new(),
+        };
+        if !dir.is_dir() {
+            return Ok(data);
+        }
+
+        let mut entries: Vec<_> = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<_, _>>()?;
+        entries.sort();
+        for path in entries {
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            if path.is_dir() && languages.iter().any(|lang| lang == name) {
+                data.languages.insert(name.to_string(), read_tree(&path)?);
+            } else {
+                insert_entry(&mut data.shared, &path)?;
+            }
+        }
+        Ok(data)
+    }
+
+    /// `site.data` for `language_code`: the shared tree with that language's files merged in
+    #[must_use]
+    pub fn for_language(&self, language_code: &str) -> Value {
+        let mut value = self.shared.clone();
+        if let Some(overrides) = self.languages.get(language_code) {
+            merge(&mut value, overrides.clone());
+        }
+        value
+    }
+}
+
+fn read_tree(dir: &Path) -> eyre::Result<Value> {
+    let mut tree = Value::Object(Map::new());
+    let mut entries: Vec<_> = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    entries.sort();
+    for path in entries {
+        insert_entry(&mut tree, &path)?;
+    }
+    Ok(tree)
+}
+
+/// Add a file or folder to `tree` under its name without extension
+fn insert_entry(tree: &mut Value, path: &Path) -> eyre::Result<()> {
+    let Some(key) = path.file_stem().and_then(|s| s.to_str()) else {
+        return Ok(());
+    };
+    if key.starts_with('.') {
+        return Ok(());
+    }
+    let value = if path.is_dir() {
+        read_tree(path)?
+    } else {
+        match read_file(path)? {
+            Some(value) => value,
+            None => return Ok(()),
+        }
+    };
+    if let Value::Object(map) = tree {
+        match map.get_mut(key) {
+            // `team.yml` next to a `team/` folder: merge the two
+            Some(existing) => merge(existing, value),
+            None => {
+                map.insert(key.to_string(), value);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parse a data file by extension; other files are skipped
+fn read_file(path: &Path) -> eyre::Result<Option<Value>> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    let Some(extension) = extension else {
+        return Ok(None);
+    };
+    if !matches!(extension.as_str(), "yml" | "yaml" | "toml" | "json" | "csv") {
+        return Ok(None);
+    }
+
+    let text = crate::shared::fs::read_file_to_string(path)?;
+    let value = match extension.as_str() {
+        "yml" | "yaml" => frontmatter::parse_yaml(path, &text, 1)?,
+        "toml" => {
+            let value: toml::Value = toml::from_str(&text)
+                .map_err(|e| eyre!("Invalid data file {}: {}", path.display(), e.message()))?;
+            serde_json::to_value(value)?
+        }
+        "json" => serde_json::from_str(&text)
+            .wrap_err_with(|| format!("Invalid data file {}", path.display()))?,
+        _ => parse_csv(&text).map_err(|e| eyre!("Invalid data file {}: {e}", path.display()))?,
+    };
+    Ok(Some(value))
+}
+
+/// Deep-merge `overlay` into `base`; objects merge key by key, anything else is replaced
+fn merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// CSV with a header row as an array of objects; fields may be quoted (RFC 4180)
+///
+/// # Errors
+///
+/// Returns an error for an unterminated quoted field or a row with more fields than the header
+pub fn parse_csv(text: &str) -> Result<Value, String> {
+    // Each row keeps the file line it starts on, since quoted fields can span lines
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_line = 1;
+    let mut quote_line = 1;
+    let mut chars = text
+        .strip_prefix('\u{feff}')
+        .unwrap_or(text)
+        .chars()
+        .peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() => {
+                in_quotes = true;
+                quote_line = line;
+            }
+            ',' if !in_quotes => row.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                row.push(std::mem::take(&mut field));
+                rows.push((row_line, std::mem::take(&mut row)));
+                line += 1;
+                row_line = line;
+            }
+            '\n' => {
+                field.push(c);
+                line += 1;
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(format!("unterminated quoted field on line {quote_line}"));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push((row_line, row));
+    }
+
+    let mut rows = rows
+        .into_iter()
+        .filter(|(_, row)| !(row.len() == 1 && row[0].trim().is_empty()));
+    let Some((_, header)) = rows.next() else {
+        return Ok(Value::Array(Vec::new()));
+    };
+    rows.map(|(line, row)| {
+        if row.len() > header.len() {
+            return Err(format!(
+                "line {line} has {} fields, the header has {}",
+                row.len(),
+                header.len()
+            ));
+        }
+        let record: Map<String, Value> = header
+            .iter()
+            .zip(row.into_iter().chain(std::iter::repeat(String::new())))
+            .map(|(key, value)| (key.trim().to_string(), Value::String(value)))
+            .collect();
+        Ok(Value::Object(record))
+    })
+    .collect::<Result<Vec<_>, _>>()
+    .map(Value::Array)
+}
@@ -0,0 +1,570 @@
+use chrono::{DateTime, FixedOffset};
+use serde_derive::Serialize;
+use serde_json::json;
+use std::fmt::Write;
+
+use crate::app::AppConfig;
+use crate::blog::Article;
+use crate::constants::files::RSS_FEED;
+use crate::posts::Post;
+use crate::shared::url::localized_path;
+
+use super::output_writer::OutputWriter;
+use super::taxonomy::{configured_taxonomies, taxonomy_field, Taxonomy};
+
+const ATOM_FEED: &str = "atom.xml";
+const JSON_FEED: &str = "feed.json";
+const DEFAULT_FEED_LIMIT: usize = 20;
+/// Frontmatter field of the taxonomy that gets per-term feeds
+const TAGS_FIELD: &str = "tags";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl FeedFormat {
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "rss" | "rss2" => Some(Self::Rss),
+            "atom" => Some(Self::Atom),
+            "json" | "jsonfeed" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::Rss => RSS_FEED,
+            Self::Atom => ATOM_FEED,
+            Self::Json => JSON_FEED,
+        }
+    }
+
+    #[must_use]
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Rss => "application/rss+xml",
+            Self::Atom => "application/atom+xml",
+            Self::Json => "application/feed+json",
+        }
+    }
+}
+
+/// A `<link rel="alternate">` entry rendered by `head.html`
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedLink {
+    pub title: String,
+    pub href: String,
+    pub mime_type: String,
+}
+
+/// One feed to render: a title, the public directory it is written to and its posts
+pub struct FeedSpec<'p> {
+    pub title: String,
+    pub description: String,
+    pub lang: String,
+    /// Public directory of the feed, e.g. `/en/seo/`
+    pub base_path: String,
+    /// Public path of the HTML page the feed mirrors
+    pub home_path: String,
+    pub posts: Vec<&'p Post>,
+}
+
+/// Builds RSS 2.0, Atom and JSON Feed 1.1 documents from article posts,
+/// one per language, per section and per tag.
+pub struct FeedGenerator<'a> {
+    app: &'a AppConfig,
+}
+
+impl<'a> FeedGenerator<'a> {
+    #[must_use]
+    pub fn new(app: &'a AppConfig) -> Self {
+        Self { app }
+    }
+
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.app
+            .feeds
+            .as_ref()
+            .and_then(|f| f.enabled)
+            .unwrap_or(true)
+    }
+
+    #[must_use]
+    pub fn formats(&self) -> Vec<FeedFormat> {
+        match self.app.feeds.as_ref().and_then(|f| f.formats.as_ref()) {
+            Some(names) => names
+                .iter()
+                .filter_map(|name| FeedFormat::from_name(name))
+                .collect(),
+            None => vec![FeedFormat::Rss, FeedFormat::Atom, FeedFormat::Json],
+        }
+    }
+
+    fn full_content(&self) -> bool {
+        self.app
+            .feeds
+            .as_ref()
+            .and_then(|f| f.full_content)
+            .unwrap_or(true)
+    }
+
+    fn limit(&self) -> usize {
+        self.app
+            .feeds
+            .as_ref()
+            .and_then(|f| f.limit)
+            .unwrap_or(DEFAULT_FEED_LIMIT)
+    }
+
+    fn section_feeds(&self) -> bool {
+        self.app
+            .feeds
+            .as_ref()
+            .and_then(|f| f.sections)
+            .unwrap_or(true)
+    }
+
+    fn tag_feeds(&self) -> bool {
+        self.app.feeds.as_ref().and_then(|f| f.tags).unwrap_or(true)
+    }
+
+    fn section_base_path(&self, language_code: &str, article: &Article) -> Option<String> {
+        let prefix = article.prefix().to_string_lossy();
+        if prefix.is_empty() {
+            return None;
+        }
+        Some(localized_path(
+            &self.app.languages.default_language,
+            language_code,
+            &format!("/{prefix}/"),
+        ))
+    }
+
+    /// Feed links for a page: the language-wide feed plus the section feed when given
+    #[must_use]
+    pub fn links(&self, language_code: &str, section: Option<&Article>) -> Vec<FeedLink> {
+        if !self.enabled() {
+            return Vec::new();
+        }
+
+        let domain = self.app.app_info.app_domain.trim_end_matches('/');
+        let mut targets = vec![(
+            self.app.app_info.app_name.clone(),
+            localized_path(&self.app.languages.default_language, language_code, "/"),
+        )];
+        if let Some(article) = section.filter(|_| self.section_feeds()) {
+            if let Some(base_path) = self.section_base_path(language_code, article) {
+                targets.push((article.title().to_string(), base_path));
+            }
+        }
+
+        let mut links = Vec::new();
+        for (title, base_path) in targets {
+            for format in self.formats() {
+                links.push(FeedLink {
+                    title: title.clone(),
+                    href: format!("{domain}{base_path}{}", format.file_name()),
+                    mime_type: format.mime_type().to_string(),
+                });
+            }
+        }
+        links
+    }
+
+    /// Collect every feed for a language: site-wide, per section and per tag
+    #[must_use]
+    pub fn feeds_for_language<'p>(
+        &self,
+        articles: &[&'p Article],
+        language_code: &str,
+    ) -> Vec<FeedSpec<'p>> {
+        let default_language = self.app.languages.default_language.as_str();
+        // Drafts are only rendered by preview builds and never syndicated
+        let mut all_posts: Vec<&Post> = articles
+            .iter()
+            .flat_map(|a| a.posts())
+            .filter(|p| !p.draft)
+            .collect();
+        sort_newest_first(&mut all_posts);
+
+        let mut feeds = vec![FeedSpec {
+            title: self.app.app_info.app_name.clone(),
+            description: self
+                .app
+                .app_info
+                .app_description
+                .clone()
+                .unwrap_or_default(),
+            lang: language_code.to_string(),
+            base_path: localized_path(default_language, language_code, "/"),
+            home_path: localized_path(default_language, language_code, "/"),
+            posts: all_posts.clone(),
+        }];
+
+        if self.section_feeds() {
+            for article in articles.iter().filter(|a| !a.draft) {
+                if let Some(base_path) = self.section_base_path(language_code, article) {
+                    let mut posts: Vec<&Post> =
+                        article.posts().iter().filter(|p| !p.draft).collect();
+                    sort_newest_first(&mut posts);
+                    feeds.push(FeedSpec {
+                        title: article.title().to_string(),
+                        description: article.description().to_string(),
+                        lang: language_code.to_string(),
+                        home_path: base_path.clone(),
+                        base_path,
+                        posts,
+                    });
+                }
+            }
+        }
+
+        // Tag feeds sit next to the term pages of the `tags` taxonomy, so none are
+        // written when that taxonomy is not configured
+        let tags = configured_taxonomies(self.app)
+            .into_iter()
+            .find(|name| taxonomy_field(name) == TAGS_FIELD);
+        if let Some(name) = tags.filter(|_| self.tag_feeds()) {
+            let taxonomy = Taxonomy::build(self.app, &name, articles, language_code);
+            for term in taxonomy.terms {
+                let mut posts: Vec<&Post> = term.posts.into_iter().filter(|p| !p.draft).collect();
+                if posts.is_empty() {
+                    continue;
+                }
+                sort_newest_first(&mut posts);
+                feeds.push(FeedSpec {
+                    title: format!("{} - #{}", self.app.app_info.app_name, term.name),
+                    description: format!("#{}", term.name),
+                    lang: language_code.to_string(),
+                    base_path: localized_path(
+                        default_language,
+                        language_code,
+                        &format!("/{name}/{}/", term.slug),
+                    ),
+                    home_path: term.url,
+                    posts,
+                });
+            }
+        }
+
+        feeds
+    }
+
+    /// Render and write every feed of a language in all configured formats
+    pub fn write_for_language(
+        &self,
+        articles: &[&Article],
+        language_code: &str,
+        output_writer: &OutputWriter,
+    ) -> eyre::Result<()> {
+        if !self.enabled() {
+            return Ok(());
+        }
+
+        let feeds = self.feeds_for_language(articles, language_code);
+        let formats = self.formats();
+        for feed in &feeds {
+            for format in &formats {
+                let content = match format {
+                    FeedFormat::Rss => self.render_rss(feed),
+                    FeedFormat::Atom => self.render_atom(feed),
+                    FeedFormat::Json => serde_json::to_string_pretty(&self.render_json(feed))?,
+                };
+                let relative_path = format!(
+                    "{}{}",
+                    feed.base_path.trim_start_matches('/'),
+                    format.file_name()
+                );
+                output_writer.write_text_file(&relative_path, &content)?;
+            }
+        }
+
+        println!(
+            "│    📰 Generated {} feeds for language: {language_code}",
+            feeds.len() * formats.len()
+        );
+        Ok(())
+    }
+
+    fn absolute(&self, path: &str) -> String {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            return path.to_string();
+        }
+        let domain = self.app.app_info.app_domain.trim_end_matches('/');
+        if path.starts_with('/') {
+            format!("{domain}{path}")
+        } else {
+            format!("{domain}/{path}")
+        }
+    }
+
+    fn post_url(&self, feed: &FeedSpec, post: &Post) -> String {
+        self.absolute(&localized_path(
+            &self.app.languages.default_language,
+            &feed.lang,
+            &post.url,
+        ))
+    }
+
+    fn limited<'f, 'p>(&self, feed: &'f FeedSpec<'p>) -> &'f [&'p Post] {
+        let limit = self.limit().min(feed.posts.len());
+        &feed.posts[..limit]
+    }
+
+    #[must_use]
+    pub fn render_rss(&self, feed: &FeedSpec) -> String {
+        let self_url = self.absolute(&format!("{}{}", feed.base_path, RSS_FEED));
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" xmlns:content=\"http://purl.org/rss/1.0/modules/content/\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n");
+        xml.push_str("  <channel>\n");
+        let _ = writeln!(xml, "    <title>{}</title>", escape(&feed.title));
+        let _ = writeln!(
+            xml,
+            "    <link>{}</link>",
+            escape(&self.absolute(&feed.home_path))
+        );
+        let _ = writeln!(
+            xml,
+            "    <description>{}</description>",
+            escape(&feed.description)
+        );
+        let _ = writeln!(xml, "    <language>{}</language>", escape(&feed.lang));
+        let _ = writeln!(xml, "    <generator>GenWebBlog</generator>");
+        if let Some(updated) = feed_updated(feed) {
+            let _ = writeln!(
+                xml,
+                "    <lastBuildDate>{}</lastBuildDate>",
+                updated.to_rfc2822()
+            );
+        }
+        let _ = writeln!(
+            xml,
+            "    <atom:link href=\"{}\" rel=\"self\" type=\"{}\"/>",
+            escape(&self_url),
+            FeedFormat::Rss.mime_type()
+        );
+
+        for post in self.limited(feed) {
+            let url = self.post_url(feed, post);
+            xml.push_str("    <item>\n");
+            let _ = writeln!(xml, "      <title>{}</title>", escape(&post.title));
+            let _ = writeln!(xml, "      <link>{}</link>", escape(&url));
+            let _ = writeln!(
+                xml,
+                "      <guid isPermaLink=\"true\">{}</guid>",
+                escape(&url)
+            );
+            if let Some(published) = parse_datetime(&post.published) {
+                let _ = writeln!(xml, "      <pubDate>{}</pubDate>", published.to_rfc2822());
+            }
+            if !post.author.is_empty() {
+                let _ = writeln!(
+                    xml,
+                    "      <dc:creator>{}</dc:creator>",
+                    escape(&post.author)
+                );
+            }
+            let _ = writeln!(
+                xml,
+                "      <description>{}</description>",
+                escape(post.meta_description())
+            );
+            if self.full_content() {
+                let _ = writeln!(
+                    xml,
+                    "      <content:encoded>{}</content:encoded>",
+                    cdata(&post.contents)
+                );
+            }
+            for tag in post.tags.iter().flatten() {
+                let _ = writeln!(xml, "      <category>{}</category>", escape(tag));
+            }
+            xml.push_str("    </item>\n");
+        }
+
+        xml.push_str("  </channel>\n");
+        xml.push_str("</rss>\n");
+        xml
+    }
+
+    #[must_use]
+    pub fn render_atom(&self, feed: &FeedSpec) -> String {
+        let self_url = self.absolute(&format!("{}{}", feed.base_path, ATOM_FEED));
+        let home_url = self.absolute(&feed.home_path);
+        let updated = feed_updated(feed).unwrap_or_else(|| chrono::Utc::now().fixed_offset());
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"{}\">",
+            escape(&feed.lang)
+        );
+        let _ = writeln!(xml, "  <title>{}</title>", escape(&feed.title));
+        if !feed.description.is_empty() {
+            let _ = writeln!(xml, "  <subtitle>{}</subtitle>", escape(&feed.description));
+        }
+        let _ = writeln!(
+            xml,
+            "  <link href=\"{}\" rel=\"self\" type=\"{}\"/>",
+            escape(&self_url),
+            FeedFormat::Atom.mime_type()
+        );
+        let _ = writeln!(
+            xml,
+            "  <link href=\"{}\" rel=\"alternate\" type=\"text/html\"/>",
+            escape(&home_url)
+        );
+        let _ = writeln!(xml, "  <id>{}</id>", escape(&home_url));
+        let _ = writeln!(xml, "  <updated>{}</updated>", updated.to_rfc3339());
+        let _ = writeln!(xml, "  <generator>GenWebBlog</generator>");
+
+        for post in self.limited(feed) {
+            let url = self.post_url(feed, post);
+            xml.push_str("  <entry>\n");
+            let _ = writeln!(xml, "    <title>{}</title>", escape(&post.title));
+            let _ = writeln!(
+                xml,
+                "    <link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>",
+                escape(&url)
+            );
+            let _ = writeln!(xml, "    <id>{}</id>", escape(&url));
+            if let Some(published) = parse_datetime(&post.published) {
+                let _ = writeln!(xml, "    <published>{}</published>", published.to_rfc3339());
+            }
+            // Every entry needs <updated>; without a date of its own it takes the feed's
+            let modified = post_updated(post)
+                .or_else(|| parse_datetime(&post.published))
+                .unwrap_or(updated);
+            let _ = writeln!(xml, "    <updated>{}</updated>", modified.to_rfc3339());
+            let author = if post.author.is_empty() {
+                self.app.app_info.app_author.clone().unwrap_or_default()
+            } else {
+                post.author.clone()
+            };
+            if !author.is_empty() {
+                let _ = writeln!(xml, "    <author><name>{}</name></author>", escape(&author));
+            }
+            if !post.meta_description().is_empty() {
+                let _ = writeln!(
+                    xml,
+                    "    <summary>{}</summary>",
+                    escape(post.meta_description())
+                );
+            }
+            if self.full_content() {
+                let _ = writeln!(
+                    xml,
+                    "    <content type=\"html\">{}</content>",
+                    escape(&post.contents)
+                );
+            }
+            for tag in post.tags.iter().flatten() {
+                let _ = writeln!(xml, "    <category term=\"{}\"/>", escape(tag));
+            }
+            xml.push_str("  </entry>\n");
+        }
+
+        xml.push_str("</feed>\n");
+        xml
+    }
+
+    #[must_use]
+    pub fn render_json(&self, feed: &FeedSpec) -> serde_json::Value {
+        let items: Vec<serde_json::Value> = self
+            .limited(feed)
+            .iter()
+            .map(|post| {
+                let url = self.post_url(feed, post);
+                let mut item = json!({
+                    "id": url,
+                    "url": url,
+                    "title": post.title,
+                    "summary": post.meta_description(),
+                    "language": feed.lang,
+                });
+                if self.full_content() {
+                    item["content_html"] = json!(post.contents);
+                } else {
+                    item["content_text"] = json!(post.meta_description());
+                }
+                if post.is_image {
+                    item["image"] = json!(self.absolute(&post.image));
+                }
+                if let Some(published) = parse_datetime(&post.published) {
+                    item["date_published"] = json!(published.to_rfc3339());
+                }
+                if let Some(modified) = post_updated(post) {
+                    item["date_modified"] = json!(modified.to_rfc3339());
+                }
+                if !post.author.is_empty() {
+                    item["authors"] = json!([{ "name": post.author }]);
+                }
+                if let Some(tags) = &post.tags {
+                    item["tags"] = json!(tags);
+                }
+                item
+            })
+            .collect();
+
+        json!({
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": feed.title,
+            "home_page_url": self.absolute(&feed.home_path),
+            "feed_url": self.absolute(&format!("{}{}", feed.base_path, JSON_FEED)),
+            "description": feed.description,
+            "language": feed.lang,
+            "items": items,
+        })
+    }
+}
+
+fn sort_newest_first(posts: &mut [&Post]) {
+    posts.sort_by(|a, b| {
+        b.year
+            .cmp(&a.year)
+            .then(b.month.cmp(&a.month))
+            .then(b.day.cmp(&a.day))
+    });
+}
+
+fn escape(value: &str) -> String {
+    html_escape::encode_quoted_attribute(value).into_owned()
+}
+
+/// Wrap HTML in a CDATA section, splitting any `]]>` that would end it early
+fn cdata(value: &str) -> String {
+    format!("<![CDATA[{}]]>", value.replace("]]>", "]]]]><![CDATA[>"))
+}
+
+fn parse_datetime(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value).ok().or_else(|| {
+        chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|dt| dt.and_utc().fixed_offset())
+    })
+}
+
+fn post_updated(post: &Post) -> Option<DateTime<FixedOffset>> {
+    post.date_modified
+        .as_deref()
+        .and_then(parse_datetime)
+        .or_else(|| parse_datetime(&post.updated))
+}
+
+fn feed_updated(feed: &FeedSpec) -> Option<DateTime<FixedOffset>> {
+    feed.posts
+        .iter()
+        .filter_map(|post| post_updated(post))
+        .max()
+}
@@ -0,0 +1,256 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, FixedOffset};
+use git2::{DiffOptions, Repository, Sort};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppConfig;
+use crate::blog::Article;
+use crate::constants::git::{CACHE_FILE, EDIT_URL_PATH};
+
+/// The last commit that touched a content file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GitCommit {
+    pub hash: String,
+    pub short_hash: String,
+    pub author: String,
+    /// Commit time, RFC 3339
+    pub date: String,
+    /// Commit day, `YYYY-MM-DD`
+    pub day: String,
+}
+
+/// Last commits by repository path, remembered for the `HEAD` they were read at
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cache {
+    head: String,
+    files: BTreeMap<String, GitCommit>,
+    /// Paths with no commit in the history of `head`
+    uncommitted: BTreeSet<String>,
+}
+
+impl Cache {
+    /// Move the paths this cache knows about from `pending` into `found`
+    fn resolve(&self, pending: &mut HashSet<&str>, found: &mut BTreeMap<String, GitCommit>) {
+        pending.retain(|path| {
+            if let Some(commit) = self.files.get(*path) {
+                found.insert((*path).to_string(), commit.clone());
+                false
+            } else {
+                !self.uncommitted.contains(*path)
+            }
+        });
+    }
+}
+
+/// Last-commit lookup for files of one repository
+pub struct GitHistory {
+    repo: Repository,
+    workdir: PathBuf,
+}
+
+impl GitHistory {
+    /// Open the repository containing `path`, if any
+    #[must_use]
+    pub fn discover(path: &Path) -> Option<Self> {
+        let repo = Repository::discover(path).ok()?;
+        let workdir = repo.workdir()?.canonicalize().ok()?;
+        Some(Self { repo, workdir })
+    }
+
+    /// `path` relative to the repository root, with `/` separators
+    #[must_use]
+    pub fn repo_path(&self, path: &Path) -> Option<String> {
+        let absolute = path.canonicalize().ok()?;
+        let relative = absolute.strip_prefix(&self.workdir).ok()?;
+        Some(relative.to_string_lossy().replace('\\', "/"))
+    }
+
+    /// Last commit of each repository path; uncommitted files are left out.
+    ///
+    /// Walks history from `HEAD` until every path is found. Results are cached in
+    /// `.git/`, so a later build only reads the commits made since.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository history cannot be read
+    pub fn last_commits(
+        &self,
+        paths: &HashSet<String>,
+    ) -> Result<BTreeMap<String, GitCommit>, git2::Error> {
+        let Ok(head) = self.repo.head().and_then(|h| h.peel_to_commit()) else {
+            // No commits yet
+            return Ok(BTreeMap::new());
+        };
+        let head_id = head.id().to_string();
+        let cache_path = self.repo.path().join(CACHE_FILE);
+        let cache: Cache = std::fs::read_to_string(&cache_path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+
+        let mut found: BTreeMap<String, GitCommit> = BTreeMap::new();
+        let mut pending: HashSet<&str> = paths.iter().map(String::as_str).collect();
+        if cache.head == head_id {
+            cache.resolve(&mut pending, &mut found);
+        }
+
+        let mut walk = self.repo.revwalk()?;
+        walk.push(head.id())?;
+        walk.set_sorting(Sort::TIME)?;
+        let mut reached_cache = false;
+        for id in walk {
+            if pending.is_empty() {
+                break;
+            }
+            let id = id?;
+            if !reached_cache && id.to_string() == cache.head {
+                // Older commits are already summarised in the cache
+                reached_cache = true;
+                cache.resolve(&mut pending, &mut found);
+                continue;
+            }
+
+            let commit = self.repo.find_commit(id)?;
+            let tree = commit.tree()?;
+            let parent_tree = match commit.parent(0) {
+                Ok(parent) => Some(parent.tree()?),
+                Err(_) => None,
+            };
+            let mut options = DiffOptions::new();
+            options.disable_pathspec_match(true);
+            for path in &pending {
+                options.pathspec(path);
+            }
+            let diff = self.repo.diff_tree_to_tree(
+                parent_tree.as_ref(),
+                Some(&tree),
+                Some(&mut options),
+            )?;
+            for delta in diff.deltas() {
+                let Some(path) = delta.new_file().path().and_then(Path::to_str) else {
+                    continue;
+                };
+                if pending.remove(path) {
+                    found.insert(path.to_string(), commit_info(&commit));
+                }
+            }
+        }
+
+        // Whatever is still pending was not found anywhere in the history
+        let (mut files, mut uncommitted) = if cache.head == head_id {
+            (cache.files, cache.uncommitted)
+        } else {
+            (BTreeMap::new(), BTreeSet::new())
+        };
+        files.extend(found.iter().map(|(k, v)| (k.clone(), v.clone())));
+        uncommitted.extend(pending.into_iter().map(str::to_string));
+        let cache = Cache {
+            head: head_id,
+            files,
+            uncommitted,
+        };
+        if let Ok(text) = serde_json::to_string(&cache) {
+            if let Err(e) = std::fs::write(&cache_path, text) {
+                warn!(
+                    "│  ⚠️ Could not write git cache {}: {e}",
+                    cache_path.display()
+                );
+            }
+        }
+
+        Ok(found)
+    }
+}
+
+fn commit_info(commit: &git2::Commit) -> GitCommit {
+    let hash = commit.id().to_string();
+    let time = commit.time();
+    let date = FixedOffset::east_opt(time.offset_minutes() * 60).and_then(|offset| {
+        DateTime::from_timestamp(time.seconds(), 0).map(|dt| dt.with_timezone(&offset))
+    });
+    GitCommit {
+        short_hash: hash.chars().take(7).collect(),
+        hash,
+        author: commit.author().name().unwrap_or_default().to_string(),
+        date: date.map(|dt| dt.to_rfc3339()).unwrap_or_default(),
+        day: date
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// `[git] edit_url` with `{path}` filled in
+#[must_use]
+pub fn edit_url(template: &str, repo_path: &str) -> String {
+    template.replace(EDIT_URL_PATH, repo_path)
+}
+
+/// Set `git`, `edit_url` and, unless given in the front matter, `date_modified`
+/// of every post from the repository the site lives in
+pub fn apply(articles: &mut [Article], app: &AppConfig) {
+    let Some(config) = app.git.as_ref() else {
+        return;
+    };
+    let last_modified = config.last_modified.unwrap_or(false);
+    if !last_modified && config.edit_url.is_none() {
+        return;
+    }
+
+    let history = GitHistory::discover(Path::new("."));
+    let repo_path = |source: &Path| -> String {
+        history
+            .as_ref()
+            .and_then(|h| h.repo_path(source))
+            .unwrap_or_else(|| source.to_string_lossy().replace('\\', "/"))
+    };
+
+    let commits = match (&history, last_modified) {
+        (Some(history), true) => {
+            let paths: HashSet<String> = articles
+                .iter()
+                .flat_map(all_posts)
+                .map(|post| repo_path(&post.source))
+                .collect();
+            history.last_commits(&paths).unwrap_or_else(|e| {
+                warn!("│  ⚠️ Could not read git history: {e}");
+                BTreeMap::new()
+            })
+        }
+        (None, true) => {
+            warn!("│  ⚠️ [git] last_modified is set but the site is not in a git repository");
+            BTreeMap::new()
+        }
+        _ => BTreeMap::new(),
+    };
+
+    for article in articles {
+        for post in article
+            .posts
+            .iter_mut()
+            .chain(article.scheduled.iter_mut())
+            .chain(article.expired.iter_mut())
+        {
+            let path = repo_path(&post.source);
+            if let Some(template) = &config.edit_url {
+                post.edit_url = Some(edit_url(template, &path));
+            }
+            if let Some(commit) = commits.get(&path) {
+                if post.date_modified.is_none() {
+                    post.date_modified = Some(commit.date.clone());
+                }
+                post.git = Some(commit.clone());
+            }
+        }
+    }
+}
+
+fn all_posts(article: &Article) -> impl Iterator<Item = &crate::posts::Post> {
+    article
+        .posts
+        .iter()
+        .chain(&article.scheduled)
+        .chain(&article.expired)
+}
@@ -1,7 +1,20 @@
 pub mod asset_manager;
+pub mod authors;
+pub mod check;
 pub mod content_parser;
+pub mod data;
+pub mod feed;
+pub mod git_info;
 pub mod output_writer;
 pub mod page_processor;
+pub mod paginator;
+pub mod redirects;
+pub mod related;
+pub mod schedule;
+pub mod series;
 pub mod site_builder;
+pub mod sitemap;
+pub mod taxonomy;
+pub mod translation_links;
 
 pub use site_builder::Generator;
@@ -38,7 +38,6 @@ impl OutputWriter {
         Ok(())
     }
 
-    #[allow(dead_code)]
     pub fn write_text_file(&self, relative_path: &str, content: &str) -> eyre::Result<()> {
         let file_path = self.out_directory.join(relative_path);
 
@@ -85,4 +84,4 @@ impl OutputWriter {
     pub fn get_output_path(&self, relative_path: &str) -> PathBuf {
         self.out_directory.join(relative_path)
     }
-}
\ No newline at end of file
+}
@@ -1,11 +1,14 @@
 use handlebars::Handlebars;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::app::AppConfig;
 use crate::blog::Article;
+use crate::constants::seo::DRAFT_ROBOTS_CONTENT;
 use crate::posts::Post;
+use crate::shared::params::Params;
 use crate::shared::read_metadata_yml::GenericYmlInfo;
+use crate::shared::url::{localized_path, slugify};
 use crate::shared::utils::{
     get_string_or_default, get_string_ref_or_default, join_keywords, DEFAULT_APPLE_TOUCH_ICON,
     DEFAULT_FAVICON_16, DEFAULT_FAVICON_32, DEFAULT_FAVICON_ICO, DEFAULT_FAVICON_SVG,
@@ -13,12 +16,28 @@ use crate::shared::utils::{
     DEFAULT_WEB_MANIFEST,
 };
 
-use super::site_builder::{get_locale_from_lang, Data};
+use super::authors::{Author, AuthorRegistry};
+use super::data::SiteData;
+use super::feed::FeedGenerator;
+use super::paginator::Paginator;
+use super::related::RelatedPosts;
+use super::series::{date_neighbours, Series};
+use super::site_builder::{get_locale_from_lang, Data, Page};
+use super::taxonomy::{Taxonomy, TaxonomyTerm};
+use super::translation_links::alternates;
 
 pub struct PageProcessor<'a> {
     handlebars: &'a Handlebars<'a>,
     app: &'a AppConfig,
     articles: &'a [Article],
+    taxonomies: HashMap<String, Vec<Taxonomy<'a>>>,
+    series: HashMap<String, BTreeMap<String, Series>>,
+    /// Related posts per language, keyed by post URL
+    related: HashMap<String, HashMap<String, Vec<&'a Post>>>,
+    author_registry: &'a AuthorRegistry,
+    /// Authors with their posts, per language
+    authors: HashMap<String, Vec<Author<'a>>>,
+    site: HashMap<String, Value>,
 }
 
 impl<'a> PageProcessor<'a> {
@@ -27,14 +46,89 @@ impl<'a> PageProcessor<'a> {
         handlebars: &'a Handlebars<'a>,
         app: &'a AppConfig,
         articles: &'a [Article],
+        author_registry: &'a AuthorRegistry,
+        data: &SiteData,
     ) -> Self {
+        let mut taxonomies = HashMap::new();
+        let mut series = HashMap::new();
+        let mut related = HashMap::new();
+        let mut authors = HashMap::new();
+        let mut site = HashMap::new();
+        for language_code in &app.languages.installed_languages {
+            let language_articles: Vec<&Article> = articles
+                .iter()
+                .filter(|a| {
+                    a.lang() == language_code
+                        || (a.lang().is_empty() && *language_code == app.languages.default_language)
+                })
+                .collect();
+            let language_taxonomies = Taxonomy::build_all(app, &language_articles, language_code);
+
+            // Exposed to templates as site.tags, site.categories, ... and site.data
+            let mut site_data = serde_json::Map::new();
+            for taxonomy in &language_taxonomies {
+                site_data.insert(
+                    taxonomy.name.clone(),
+                    serde_json::to_value(&taxonomy.terms).unwrap_or(Value::Null),
+                );
+            }
+
+            site_data.insert("data".to_string(), data.for_language(language_code));
+
+            site.insert(language_code.clone(), Value::Object(site_data));
+            taxonomies.insert(language_code.clone(), language_taxonomies);
+            series.insert(
+                language_code.clone(),
+                Series::build_all(app, &language_articles, language_code),
+            );
+            let language_posts: Vec<&Post> =
+                language_articles.iter().flat_map(|a| a.posts()).collect();
+            related.insert(
+                language_code.clone(),
+                RelatedPosts::new(app).compute(&language_posts),
+            );
+            authors.insert(
+                language_code.clone(),
+                author_registry.build_all(app, &language_articles, language_code),
+            );
+        }
+
         Self {
             handlebars,
             app,
             articles,
+            taxonomies,
+            series,
+            related,
+            author_registry,
+            authors,
+            site,
         }
     }
 
+    #[must_use]
+    pub fn taxonomies_for_language(&self, language_code: &str) -> &[Taxonomy<'a>] {
+        self.taxonomies
+            .get(language_code)
+            .map_or(&[][..], Vec::as_slice)
+    }
+
+    /// Every series of a language, ordered by slug
+    pub fn series_for_language(&self, language_code: &str) -> impl Iterator<Item = &Series> {
+        self.series
+            .get(language_code)
+            .into_iter()
+            .flat_map(BTreeMap::values)
+    }
+
+    /// Registered authors with their posts, ordered by id
+    #[must_use]
+    pub fn authors_for_language(&self, language_code: &str) -> &[Author<'a>] {
+        self.authors
+            .get(language_code)
+            .map_or(&[][..], Vec::as_slice)
+    }
+
     fn collect_all_posts_for_language(&self, language_code: &str) -> Vec<Post> {
         // estimate capacity: sum of posts lengths for matching articles
         let mut capacity = 0usize;
@@ -85,12 +179,15 @@ impl<'a> PageProcessor<'a> {
         v
     }
 
+    /// Render a page from its yml. Listings (the articles page, section pages) pass
+    /// the `pager` of the page being rendered so only its slice of posts is shown.
     pub fn process_article_page(
         &self,
         article: &Article,
         yml_info: &GenericYmlInfo,
         language_code: &str,
         translations: &HashMap<String, String>,
+        pager: Option<&Paginator>,
     ) -> eyre::Result<String> {
         let mut data = self.create_page_data(article, yml_info, language_code, translations);
 
@@ -105,12 +202,27 @@ impl<'a> PageProcessor<'a> {
         // collect all posts across articles (global list)
         data.all_posts = Some(self.collect_all_posts_for_language(language_code));
 
+        // The articles page lists every post of the language, not only the first section's
+        if yml_info.page_name == "articles" {
+            data.posts.clone_from(&data.all_posts);
+        }
+
+        // Section pages also advertise the section feed
+        if article.prefix().to_string_lossy() == yml_info.page_name {
+            data.feeds = FeedGenerator::new(self.app).links(language_code, Some(article));
+        }
+
         data.type_page = self.get_type_page_for_template(&yml_info.page_name);
+        Self::apply_pagination(&mut data, pager, translations);
+        if pager.is_some() && yml_info.page_name == "articles" {
+            data.all_posts.clone_from(&data.posts);
+        }
 
         let content = self.handlebars.render(template_name, &data)?;
         Ok(content)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn process_index_page(
         &self,
         article: &Article,
@@ -119,6 +231,7 @@ impl<'a> PageProcessor<'a> {
         translations: &HashMap<String, String>,
         articles: Vec<Value>,
         all_articles: &[&Article],
+        pager: Option<&Paginator>,
     ) -> eyre::Result<String> {
         let mut data = self.create_page_data(article, yml_info, language_code, translations);
         data.articles = Some(articles);
@@ -127,10 +240,243 @@ impl<'a> PageProcessor<'a> {
 
         data.type_page = self.get_type_page_for_template("index");
 
+        Self::apply_pagination(&mut data, pager, translations);
+        // Home layouts list either `posts` or `all_posts`; keep both on the current page
+        if pager.is_some() {
+            data.all_posts.clone_from(&data.posts);
+        }
+
         let content = self.handlebars.render("index", &data)?;
         Ok(content)
     }
 
+    /// Render a taxonomy index (`/tags/`) or, when `term` is given, a term listing (`/tags/<tag>/`)
+    pub fn process_taxonomy_page<'b>(
+        &self,
+        article: &'b Article,
+        taxonomy: &'b Taxonomy<'b>,
+        term: Option<&'b TaxonomyTerm<'b>>,
+        language_code: &str,
+        translations: &HashMap<String, String>,
+        pager: Option<&Paginator>,
+    ) -> eyre::Result<String> {
+        let taxonomy_title = translations
+            .get(&taxonomy.name)
+            .cloned()
+            .unwrap_or_else(|| {
+                let mut chars = taxonomy.name.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_uppercase().collect::<String>() + chars.as_str()
+                })
+            });
+
+        let (page_name, title, description, template_name) = match term {
+            Some(term) => (
+                format!("{}/{}", taxonomy.name, term.slug),
+                format!("{taxonomy_title}: {}", term.name),
+                format!("{taxonomy_title}: {} ({})", term.name, term.count),
+                "taxonomy_term",
+            ),
+            None => (
+                taxonomy.name.clone(),
+                taxonomy_title.clone(),
+                taxonomy_title.clone(),
+                "taxonomy",
+            ),
+        };
+
+        let taxonomy_yml = GenericYmlInfo {
+            page_name,
+            title,
+            description,
+            keywords: term.map(|t| vec![t.name.clone()]).unwrap_or_default(),
+            image: None,
+            draft: None,
+            date_published: None,
+            date_modified: None,
+            lang: Some(language_code.to_string()),
+            layout: Some(template_name.to_string()),
+            category: None,
+            link_text: None,
+            author: None,
+            author_url: None,
+            author_email: None,
+            paginate: None,
+            params: Params::new(),
+            content: None,
+        };
+
+        let mut data = self.create_page_data(article, &taxonomy_yml, language_code, translations);
+        data.title.clone_from(&taxonomy_yml.title);
+        data.description.clone_from(&taxonomy_yml.description);
+        data.posts = None;
+        data.taxonomy = Some(taxonomy);
+        data.term = term;
+        data.type_page = "CollectionPage".to_string();
+        Self::apply_pagination(&mut data, pager, translations);
+        data.posts = Some(Self::page_posts(
+            term.map_or(&[][..], |t| t.posts.as_slice()),
+            pager,
+        ));
+
+        let content = self.handlebars.render(template_name, &data)?;
+        Ok(content)
+    }
+
+    /// Render the author index (`/authors/`) or one author's profile and posts (`/authors/<id>/`)
+    pub fn process_author_page<'b>(
+        &self,
+        article: &'b Article,
+        author: Option<&'b Author<'b>>,
+        language_code: &str,
+        translations: &HashMap<String, String>,
+        pager: Option<&Paginator>,
+    ) -> eyre::Result<String> {
+        let authors_title = translations
+            .get("authors")
+            .cloned()
+            .unwrap_or_else(|| "Authors".to_string());
+
+        let (page_name, title, description, template_name) = match author {
+            Some(author) => (
+                format!("authors/{}", author.id),
+                author.name.clone(),
+                author
+                    .bio
+                    .clone()
+                    .unwrap_or_else(|| format!("{authors_title}: {}", author.name)),
+                "author",
+            ),
+            None => (
+                "authors".to_string(),
+                authors_title.clone(),
+                authors_title.clone(),
+                "authors",
+            ),
+        };
+
+        let author_yml = GenericYmlInfo {
+            page_name,
+            title,
+            description,
+            keywords: author.map(|a| vec![a.name.clone()]).unwrap_or_default(),
+            image: author.and_then(|a| a.avatar.clone()),
+            draft: None,
+            date_published: None,
+            date_modified: None,
+            lang: Some(language_code.to_string()),
+            layout: Some(template_name.to_string()),
+            category: None,
+            link_text: None,
+            author: author.map(|a| a.name.clone()),
+            author_url: None,
+            author_email: None,
+            paginate: None,
+            params: Params::new(),
+            content: None,
+        };
+
+        let mut data = self.create_page_data(article, &author_yml, language_code, translations);
+        data.title.clone_from(&author_yml.title);
+        data.description.clone_from(&author_yml.description);
+        data.posts = None;
+        match author {
+            Some(author) => {
+                data.author = Some(author.clone());
+                data.type_page = "ProfilePage".to_string();
+            }
+            None => {
+                data.authors = Some(self.authors_for_language(language_code).to_vec());
+                data.type_page = "CollectionPage".to_string();
+            }
+        }
+        Self::apply_pagination(&mut data, pager, translations);
+        data.posts = Some(Self::page_posts(
+            author.map_or(&[][..], |a| a.posts.as_slice()),
+            pager,
+        ));
+
+        let content = self.handlebars.render(template_name, &data)?;
+        Ok(content)
+    }
+
+    /// Copies of the posts on the current page of a listing kept as references
+    fn page_posts(posts: &[&Post], pager: Option<&Paginator>) -> Vec<Post> {
+        pager
+            .map_or(posts, |pager| pager.slice(posts))
+            .iter()
+            .map(|post| (*post).clone())
+            .collect()
+    }
+
+    /// Narrow `data.posts` to the current page and point the
+    /// page URL (canonical, og:url) at `/page/N/` for every page after the first
+    fn apply_pagination(
+        data: &mut Data,
+        pager: Option<&Paginator>,
+        translations: &HashMap<String, String>,
+    ) {
+        let Some(pager) = pager else {
+            return;
+        };
+
+        if let Some(posts) = data.posts.as_mut() {
+            *posts = pager.slice(posts).to_vec();
+        }
+
+        if pager.page > 1 {
+            let page_label = translations
+                .get("pagination_page")
+                .map_or("Page", String::as_str);
+            data.title = format!("{} - {page_label} {}", data.title, pager.page);
+            data.path = pager.url.clone();
+            data.url = format!("{}{}", data.site_root.trim_end_matches('/'), pager.url);
+        }
+
+        data.pagination = Some(pager.clone());
+    }
+
+    /// Render the landing page of a series (`/series/<slug>/`) listing its parts in order
+    pub fn process_series_page(
+        &self,
+        article: &Article,
+        series: &Series,
+        language_code: &str,
+        translations: &HashMap<String, String>,
+    ) -> eyre::Result<String> {
+        let series_label = translations.get("series").map_or("Series", String::as_str);
+
+        let series_yml = GenericYmlInfo {
+            page_name: format!("series/{}", series.slug),
+            title: series.name.clone(),
+            description: format!("{series_label}: {} ({})", series.name, series.posts.len()),
+            keywords: vec![series.name.clone()],
+            image: None,
+            draft: None,
+            date_published: None,
+            date_modified: None,
+            lang: Some(language_code.to_string()),
+            layout: Some("series".to_string()),
+            category: None,
+            link_text: None,
+            author: None,
+            author_url: None,
+            author_email: None,
+            paginate: None,
+            params: Params::new(),
+            content: None,
+        };
+
+        let mut data = self.create_page_data(article, &series_yml, language_code, translations);
+        data.title.clone_from(&series_yml.title);
+        data.description.clone_from(&series_yml.description);
+        data.posts = Some(series.posts.clone());
+        data.type_page = "CollectionPage".to_string();
+
+        let content = self.handlebars.render("series", &data)?;
+        Ok(content)
+    }
+
     fn get_type_page_for_template(&self, template_name: &str) -> String {
         if let Some(seo) = &self.app.seo {
             match template_name {
@@ -207,7 +553,7 @@ impl<'a> PageProcessor<'a> {
             )
         };
 
-            Data {
+        Data {
             lang: language_code.to_string(),
             locale: Some(locale.clone()),
             locale_alternate: Some(if language_code == self.app.languages.default_language {
@@ -241,6 +587,26 @@ impl<'a> PageProcessor<'a> {
             date_published: None,
             category: None,
             translations: Some(translations.clone()),
+            feeds: FeedGenerator::new(self.app).links(language_code, None),
+            site: self
+                .site
+                .get(language_code)
+                .cloned()
+                .unwrap_or_else(|| Value::Object(serde_json::Map::new())),
+            taxonomy: None,
+            term: None,
+            pagination: None,
+            series: None,
+            prev_post: None,
+            next_post: None,
+            related_posts: None,
+            authors: None,
+            author: None,
+            alternates: Vec::new(),
+            page: Page {
+                params: yml_info.params.clone(),
+                content: yml_info.content.clone(),
+            },
             app_name: self.app.app_info.app_name.clone(),
             app_author: get_string_or_default(&self.app.app_info.app_author, "").to_string(),
             app_version: self.app.app_info.app_version.clone(),
@@ -306,15 +672,20 @@ impl<'a> PageProcessor<'a> {
             twitter_site: get_string_or_default(&self.app.twitter.twitter_site, "").to_string(),
             twitter_creator: get_string_or_default(&self.app.twitter.twitter_creator, "")
                 .to_string(),
-            robots_content: get_string_ref_or_default(
-                &self
-                    .app
-                    .seo
-                    .as_ref()
-                    .and_then(|s| s.robots_content.as_ref()),
-                DEFAULT_ROBOTS_CONTENT,
-            )
-            .to_string(),
+            // Drafts only show up in preview builds and must never be indexed
+            robots_content: if yml_info.draft == Some(true) {
+                DRAFT_ROBOTS_CONTENT.to_string()
+            } else {
+                get_string_ref_or_default(
+                    &self
+                        .app
+                        .seo
+                        .as_ref()
+                        .and_then(|s| s.robots_content.as_ref()),
+                    DEFAULT_ROBOTS_CONTENT,
+                )
+                .to_string()
+            },
             og_type: get_string_ref_or_default(
                 &self
                     .app
@@ -364,12 +735,16 @@ impl<'a> PageProcessor<'a> {
         let post_yml = GenericYmlInfo {
             page_name: post.filename.clone(),
             title: post.title.clone(),
-            description: post.description.clone(),
+            description: post.meta_description().to_string(),
             keywords: vec![post.keywords.clone()],
             image: Some(post.image.clone()),
             draft: Some(post.draft),
             date_published: Some(post.published.clone()),
-            date_modified: Some(post.updated.clone()),
+            date_modified: Some(
+                post.date_modified
+                    .clone()
+                    .unwrap_or_else(|| post.updated.clone()),
+            ),
             lang: Some(language_code.to_string()),
             layout: Some(post.layout.clone()),
             category: post.category.clone(),
@@ -377,12 +752,42 @@ impl<'a> PageProcessor<'a> {
             author: Some(post.author.clone()),
             author_url: Some(post.author_url.clone()),
             author_email: Some(post.author_email.clone()),
+            paginate: None,
+            params: post.params.clone(),
+            content: None,
         };
 
         let mut data = self.create_page_data(article, &post_yml, language_code, translations);
 
         data.post = Some(post.clone());
+        data.date_published.clone_from(&post_yml.date_published);
+        data.date_modified.clone_from(&post_yml.date_modified);
+        data.alternates = alternates(post, self.app);
         data.type_page = "BlogPosting".to_string();
+        data.feeds = FeedGenerator::new(self.app).links(language_code, Some(article));
+        data.series = post
+            .series
+            .as_deref()
+            .and_then(|name| self.series.get(language_code)?.get(&slugify(name)))
+            .and_then(|series| series.nav_for(post, self.app, language_code));
+        (data.prev_post, data.next_post) = date_neighbours(article, post, self.app, language_code);
+        let post_authors = self.author_registry.for_post(post, self.app, language_code);
+        data.authors = (!post_authors.is_empty()).then_some(post_authors);
+        data.related_posts = self
+            .related
+            .get(language_code)
+            .and_then(|related| related.get(&post.url))
+            .filter(|posts| !posts.is_empty())
+            .map(|posts| {
+                let default_language = self.app.languages.default_language.as_str();
+                posts
+                    .iter()
+                    .map(|p| Post {
+                        url: localized_path(default_language, language_code, &p.url),
+                        ..(*p).clone()
+                    })
+                    .collect()
+            });
 
         let post_path = if language_code == self.app.languages.default_language {
             post.url.clone()
@@ -0,0 +1,136 @@
+use serde_derive::Serialize;
+
+use crate::app::AppConfig;
+use crate::constants::pagination::DEFAULT_PAGE_SIZE;
+
+use super::site_builder::page_output_path;
+use super::sitemap::output_path_to_url;
+
+/// A link to one page of a paginated listing
+#[derive(Debug, Clone, Serialize)]
+pub struct PageLink {
+    pub number: usize,
+    pub url: String,
+    pub current: bool,
+}
+
+/// One page of a listing, exposed to templates as `pagination`.
+///
+/// Page 1 is served from the listing's own URL (`/articles/`), later pages
+/// from `/articles/page/2/`, `/articles/page/3/`, ...
+#[derive(Debug, Clone, Serialize)]
+pub struct Paginator {
+    /// Current page number, starting at 1
+    pub page: usize,
+    pub per_page: usize,
+    /// Number of pages
+    pub total: usize,
+    pub total_items: usize,
+    pub url: String,
+    pub first: String,
+    pub last: String,
+    pub prev: Option<String>,
+    pub next: Option<String>,
+    pub pages: Vec<PageLink>,
+    #[serde(skip)]
+    page_name: String,
+    #[serde(skip)]
+    start: usize,
+    #[serde(skip)]
+    end: usize,
+}
+
+impl Paginator {
+    /// Split `total_items` into pages of `per_page` items for the listing at `base_page_name`
+    /// (`index`, `articles`, `seo`, `tags/rust`, ...). A `per_page` of 0 keeps everything on one page.
+    #[must_use]
+    pub fn paginate(
+        app: &AppConfig,
+        language_code: &str,
+        base_page_name: &str,
+        total_items: usize,
+        per_page: usize,
+    ) -> Vec<Self> {
+        let total = if per_page == 0 {
+            1
+        } else {
+            total_items.div_ceil(per_page).max(1)
+        };
+
+        let names: Vec<String> = (1..=total)
+            .map(|n| page_name_for(base_page_name, n))
+            .collect();
+        let urls: Vec<String> = names
+            .iter()
+            .map(|name| output_path_to_url(&page_output_path(app, language_code, name)))
+            .collect();
+
+        (1..=total)
+            .map(|page| {
+                let (start, end) = if per_page == 0 {
+                    (0, total_items)
+                } else {
+                    let start = (page - 1) * per_page;
+                    (start, (start + per_page).min(total_items))
+                };
+
+                Self {
+                    page,
+                    per_page: if per_page == 0 { total_items } else { per_page },
+                    total,
+                    total_items,
+                    url: urls[page - 1].clone(),
+                    first: urls[0].clone(),
+                    last: urls[total - 1].clone(),
+                    prev: (page > 1).then(|| urls[page - 2].clone()),
+                    next: (page < total).then(|| urls[page].clone()),
+                    pages: urls
+                        .iter()
+                        .enumerate()
+                        .map(|(i, url)| PageLink {
+                            number: i + 1,
+                            url: url.clone(),
+                            current: i + 1 == page,
+                        })
+                        .collect(),
+                    page_name: names[page - 1].clone(),
+                    start,
+                    end,
+                }
+            })
+            .collect()
+    }
+
+    /// Items of `items` that belong on this page
+    #[must_use]
+    pub fn slice<'b, T>(&self, items: &'b [T]) -> &'b [T] {
+        let end = self.end.min(items.len());
+        items.get(self.start.min(end)..end).unwrap_or(&[])
+    }
+
+    /// Page name to pass to `page_output_path`, e.g. `articles/page/2`
+    #[must_use]
+    pub fn page_name(&self) -> &str {
+        &self.page_name
+    }
+}
+
+/// Page name of page `number` of the listing at `base_page_name`
+#[must_use]
+pub fn page_name_for(base_page_name: &str, number: usize) -> String {
+    if number <= 1 {
+        base_page_name.to_string()
+    } else if base_page_name == "index" {
+        format!("page/{number}")
+    } else {
+        format!("{base_page_name}/page/{number}")
+    }
+}
+
+/// Page size for a listing: the section's own `paginate` setting, else `[pagination] page_size`
+#[must_use]
+pub fn page_size(app: &AppConfig, section_page_size: Option<usize>) -> usize {
+    section_page_size
+        .or_else(|| app.pagination.as_ref().and_then(|p| p.page_size))
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+}
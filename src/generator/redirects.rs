@@ -0,0 +1,208 @@
+use eyre::eyre;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::app::AppConfig;
+use crate::blog::Article;
+use crate::constants::files::REDIRECTS;
+use crate::shared::url::localized_path;
+
+use super::output_writer::OutputWriter;
+
+/// A permanent redirect from an old site path to a page or external URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redirect {
+    pub from: String,
+    pub to: String,
+    pub lang: String,
+    /// Where it was declared, for error messages
+    pub source: String,
+}
+
+/// Redirects from post `aliases:` and `[redirects]` in `app.toml`.
+///
+/// Each one becomes a meta-refresh stub in the build (so it works on any static host)
+/// and a line in `_redirects` for Cloudflare Pages and Netlify, which serve a real 301.
+#[derive(Debug, Default)]
+pub struct Redirects {
+    redirects: Vec<Redirect>,
+}
+
+impl Redirects {
+    /// Collect every redirect of the build
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when one path redirects to two different targets, or to itself
+    pub fn collect(app: &AppConfig, articles: &[Article], drafts: bool) -> eyre::Result<Self> {
+        let default_language = app.languages.default_language.as_str();
+        let mut redirects = Vec::new();
+
+        for (from, to) in app.redirects.iter().flatten() {
+            redirects.push(Redirect {
+                from: normalize(from),
+                to: to.trim().to_string(),
+                lang: default_language.to_string(),
+                source: "[redirects] in app.toml".to_string(),
+            });
+        }
+
+        for article in articles {
+            let lang = if article.lang().is_empty() {
+                default_language
+            } else {
+                article.lang().as_str()
+            };
+            for post in article.posts() {
+                if post.draft && !drafts {
+                    continue;
+                }
+                for alias in &post.aliases {
+                    redirects.push(Redirect {
+                        from: normalize(alias),
+                        to: localized_path(default_language, lang, &post.url),
+                        lang: lang.to_string(),
+                        source: format!("aliases of \"{}\"", post.title),
+                    });
+                }
+            }
+        }
+
+        let mut seen: BTreeMap<&str, &Redirect> = BTreeMap::new();
+        for redirect in &redirects {
+            if normalize(&redirect.to) == redirect.from {
+                return Err(eyre!(
+                    "Redirect {} points to itself ({})",
+                    redirect.from,
+                    redirect.source
+                ));
+            }
+            if let Some(other) = seen.insert(&redirect.from, redirect) {
+                if other.to != redirect.to {
+                    return Err(eyre!(
+                        "Redirect {} is declared twice with different targets: {} ({}) and {} ({})",
+                        redirect.from,
+                        other.to,
+                        other.source,
+                        redirect.to,
+                        redirect.source
+                    ));
+                }
+            }
+        }
+        redirects.sort_by(|a, b| a.from.cmp(&b.from));
+        redirects.dedup_by(|a, b| a.from == b.from);
+
+        Ok(Self { redirects })
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.redirects.is_empty()
+    }
+
+    #[must_use]
+    pub fn redirects(&self) -> &[Redirect] {
+        &self.redirects
+    }
+
+    /// Write a meta-refresh stub for every redirect. Call after all pages are written:
+    /// a stub that would overwrite a page, or shadow a file in `public/`, fails the build.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on a collision with a real page or when a file cannot be written
+    pub fn write_stubs(&self, app: &AppConfig, output_writer: &OutputWriter) -> eyre::Result<()> {
+        for redirect in &self.redirects {
+            let stub_path = stub_output_path(&redirect.from);
+            if output_writer.get_output_path(&stub_path).exists()
+                || Path::new("public").join(&stub_path).exists()
+            {
+                return Err(eyre!(
+                    "Redirect {} ({}) collides with an existing page at {stub_path}",
+                    redirect.from,
+                    redirect.source
+                ));
+            }
+            output_writer.write_html_file(&stub_path, &stub_html(app, redirect))?;
+        }
+        Ok(())
+    }
+
+    /// Append the redirects to `_redirects`, keeping the rules copied from `public/_redirects`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written
+    pub fn write_redirects_file(&self, output_writer: &OutputWriter) -> eyre::Result<()> {
+        if self.redirects.is_empty() {
+            return Ok(());
+        }
+        let existing =
+            std::fs::read_to_string(output_writer.get_output_path(REDIRECTS)).unwrap_or_default();
+        output_writer.write_text_file(REDIRECTS, &self.redirects_file(&existing))
+    }
+
+    /// `_redirects` contents: `existing` rules first, then one `from to 301` line per redirect
+    #[must_use]
+    pub fn redirects_file(&self, existing: &str) -> String {
+        let mut content = existing.trim_end().to_string();
+        if !content.is_empty() {
+            content.push('\n');
+        }
+        content.push_str("# Generated by genwebblog from aliases and [redirects]\n");
+        for redirect in &self.redirects {
+            content.push_str(&format!("{} {} 301\n", redirect.from, redirect.to));
+        }
+        content
+    }
+}
+
+/// `old-post/` and `/old-post` are the same redirect source
+fn normalize(path: &str) -> String {
+    let path = path.trim();
+    if path.starts_with("http://") || path.starts_with("https://") {
+        return path.to_string();
+    }
+    format!("/{}", path.trim_matches('/'))
+}
+
+/// `/old.html` is written as is, `/old` as `old/index.html` so `/old/` resolves too
+#[must_use]
+pub fn stub_output_path(from: &str) -> String {
+    let path = from.trim_matches('/');
+    if path.ends_with(".html") {
+        path.to_string()
+    } else if path.is_empty() {
+        "index.html".to_string()
+    } else {
+        format!("{path}/index.html")
+    }
+}
+
+fn stub_html(app: &AppConfig, redirect: &Redirect) -> String {
+    let target = if redirect.to.starts_with("http://") || redirect.to.starts_with("https://") {
+        redirect.to.clone()
+    } else {
+        format!("{}{}", app.app_info.app_domain, redirect.to)
+    };
+    let target = html_escape::encode_double_quoted_attribute(&target);
+    let to = html_escape::encode_double_quoted_attribute(&redirect.to);
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="{lang}">
+<head>
+<meta charset="utf-8">
+<title>Redirecting…</title>
+<link rel="canonical" href="{target}">
+<meta name="robots" content="noindex">
+<meta http-equiv="refresh" content="0; url={to}">
+</head>
+<body>
+<p><a href="{to}">{to}</a></p>
+</body>
+</html>
+"#,
+        lang = redirect.lang
+    )
+}
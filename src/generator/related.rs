@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::app::AppConfig;
+use crate::constants::related::{
+    DEFAULT_CATEGORY_WEIGHT, DEFAULT_KEYWORD_WEIGHT, DEFAULT_LIMIT, DEFAULT_TAG_WEIGHT,
+    MIN_SIMILARITY, SIMILARITY_TEXT_CHARS,
+};
+use crate::posts::Post;
+use crate::shared::url::slugify;
+use crate::shared::utils::strip_html_tags;
+
+/// What a post is compared on: its taxonomy terms and a bag of character bigrams
+struct Profile {
+    tags: HashSet<String>,
+    category: Option<String>,
+    keywords: HashSet<String>,
+    bigrams: HashMap<(char, char), f64>,
+    norm: f64,
+}
+
+/// Computes "read next" suggestions for every post of a language.
+///
+/// Posts are scored on shared tags, category and keywords (weights from `[related]`),
+/// plus the cosine similarity of character bigrams of their title, description and
+/// opening text. Bigrams need no word segmentation, so the fallback works for Thai too.
+pub struct RelatedPosts<'a> {
+    app: &'a AppConfig,
+}
+
+impl<'a> RelatedPosts<'a> {
+    #[must_use]
+    pub fn new(app: &'a AppConfig) -> Self {
+        Self { app }
+    }
+
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.app
+            .related
+            .as_ref()
+            .and_then(|r| r.enabled)
+            .unwrap_or(true)
+    }
+
+    fn limit(&self) -> usize {
+        self.app
+            .related
+            .as_ref()
+            .and_then(|r| r.limit)
+            .unwrap_or(DEFAULT_LIMIT)
+    }
+
+    fn weights(&self) -> (f64, f64, f64) {
+        let related = self.app.related.as_ref();
+        (
+            related
+                .and_then(|r| r.tag_weight)
+                .unwrap_or(DEFAULT_TAG_WEIGHT),
+            related
+                .and_then(|r| r.category_weight)
+                .unwrap_or(DEFAULT_CATEGORY_WEIGHT),
+            related
+                .and_then(|r| r.keyword_weight)
+                .unwrap_or(DEFAULT_KEYWORD_WEIGHT),
+        )
+    }
+
+    /// Related posts keyed by post URL, best match first
+    #[must_use]
+    pub fn compute<'p>(&self, posts: &[&'p Post]) -> HashMap<String, Vec<&'p Post>> {
+        let mut related = HashMap::new();
+        if !self.enabled() || self.limit() == 0 {
+            return related;
+        }
+
+        let (tag_weight, category_weight, keyword_weight) = self.weights();
+        let profiles: Vec<Profile> = posts.iter().map(|p| profile(p)).collect();
+
+        for (i, post) in posts.iter().enumerate() {
+            let mut scored: Vec<(f64, usize)> = posts
+                .iter()
+                .enumerate()
+                .filter(|(j, other)| *j != i && !other.draft && other.url != post.url)
+                .filter_map(|(j, _)| {
+                    let (a, b) = (&profiles[i], &profiles[j]);
+                    let shared_tags = a.tags.intersection(&b.tags).count();
+                    let shared_keywords = a.keywords.intersection(&b.keywords).count();
+                    let same_category = a.category.is_some() && a.category == b.category;
+                    let similarity = cosine(a, b);
+
+                    #[allow(clippy::cast_precision_loss)]
+                    let overlap = tag_weight * shared_tags as f64
+                        + keyword_weight * shared_keywords as f64
+                        + if same_category { category_weight } else { 0.0 };
+
+                    // Text similarity breaks ties and is the only signal for untagged posts
+                    if overlap > 0.0 || similarity >= MIN_SIMILARITY {
+                        Some((overlap + similarity, j))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            scored.sort_by(|(score_a, a), (score_b, b)| {
+                score_b.total_cmp(score_a).then_with(|| {
+                    let (a, b) = (posts[*a], posts[*b]);
+                    (b.year, b.month, b.day).cmp(&(a.year, a.month, a.day))
+                })
+            });
+
+            related.insert(
+                post.url.clone(),
+                scored
+                    .into_iter()
+                    .take(self.limit())
+                    .map(|(_, j)| posts[j])
+                    .collect(),
+            );
+        }
+
+        related
+    }
+}
+
+fn profile(post: &Post) -> Profile {
+    let terms = |values: &[String]| -> HashSet<String> {
+        values
+            .iter()
+            .map(|v| slugify(v))
+            .filter(|v| !v.is_empty())
+            .collect()
+    };
+
+    let keywords: Vec<String> = post.keywords.split(',').map(str::to_string).collect();
+
+    let body = strip_html_tags(&post.contents);
+    let text = format!(
+        "{} {} {} {}",
+        post.title, post.description, post.keywords, body
+    );
+
+    let mut bigrams: HashMap<(char, char), f64> = HashMap::new();
+    let normalized: Vec<char> = text
+        .chars()
+        .take(SIMILARITY_TEXT_CHARS)
+        .flat_map(char::to_lowercase)
+        .map(|c| {
+            if c.is_alphanumeric() || is_mark(c) {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    for pair in normalized.windows(2) {
+        if pair[0] != ' ' && pair[1] != ' ' {
+            *bigrams.entry((pair[0], pair[1])).or_insert(0.0) += 1.0;
+        }
+    }
+    let norm = bigrams.values().map(|v| v * v).sum::<f64>().sqrt();
+
+    Profile {
+        tags: terms(post.tags.as_deref().unwrap_or_default()),
+        category: post
+            .category
+            .as_deref()
+            .map(slugify)
+            .filter(|c| !c.is_empty()),
+        keywords: terms(&keywords),
+        bigrams,
+        norm,
+    }
+}
+
+fn cosine(a: &Profile, b: &Profile) -> f64 {
+    if a.norm == 0.0 || b.norm == 0.0 {
+        return 0.0;
+    }
+    let (small, large) = if a.bigrams.len() < b.bigrams.len() {
+        (&a.bigrams, &b.bigrams)
+    } else {
+        (&b.bigrams, &a.bigrams)
+    };
+    let dot: f64 = small
+        .iter()
+        .filter_map(|(k, v)| large.get(k).map(|w| v * w))
+        .sum();
+    dot / (a.norm * b.norm)
+}
+
+/// Thai vowel and tone marks are not alphanumeric but belong to the word
+fn is_mark(c: char) -> bool {
+    matches!(c, '\u{0E31}' | '\u{0E34}'..='\u{0E3A}' | '\u{0E47}'..='\u{0E4E}')
+}
@@ -0,0 +1,182 @@
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::blog::Article;
+use crate::posts::Post;
+
+/// Options for a single build, set from the command line (`build --future --drafts`)
+#[derive(Debug, Clone)]
+pub struct BuildOptions {
+    /// Publish posts dated in the future instead of holding them back
+    pub future: bool,
+    /// Preview build: render draft posts and sections, marked `noindex`
+    pub drafts: bool,
+    /// Moment publish and expiry dates are compared against
+    pub now: DateTime<Utc>,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        Self {
+            future: false,
+            drafts: false,
+            now: Utc::now(),
+        }
+    }
+}
+
+impl BuildOptions {
+    /// Read build flags from the arguments following the command
+    #[must_use]
+    pub fn from_args(args: &[String]) -> Self {
+        Self {
+            future: args.iter().any(|a| a == "--future"),
+            drafts: args.iter().any(|a| a == "--drafts"),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostStatus {
+    Published,
+    /// Publish date is still in the future
+    Scheduled,
+    /// `expiry_date` has passed
+    Expired,
+}
+
+/// Whether a post goes into this build
+#[must_use]
+pub fn post_status(post: &Post, options: &BuildOptions) -> PostStatus {
+    let now = options.now.fixed_offset();
+
+    if post
+        .expiry_date
+        .as_deref()
+        .and_then(parse_date)
+        .is_some_and(|expiry| expiry <= now)
+    {
+        return PostStatus::Expired;
+    }
+
+    if !options.future && publish_date(post).is_some_and(|date| date > now) {
+        return PostStatus::Scheduled;
+    }
+
+    PostStatus::Published
+}
+
+/// Moment a post goes live: its resolved `published` date, so a post is scheduled by
+/// the same date it shows (`date`, else `date_published`, else the filename date)
+#[must_use]
+pub fn publish_date(post: &Post) -> Option<DateTime<FixedOffset>> {
+    parse_date(&post.published)
+}
+
+/// Parse an RFC 3339 datetime, `YYYY-MM-DD HH:MM:SS` or `YYYY-MM-DD` (both read as UTC)
+#[must_use]
+pub fn parse_date(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|dt| dt.and_utc().fixed_offset())
+        })
+        .or_else(|| {
+            chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|dt| dt.and_utc().fixed_offset())
+        })
+}
+
+/// A post left out of the build, with the date that decided it
+#[derive(Debug, Clone)]
+pub struct HeldBackPost {
+    pub title: String,
+    pub url: String,
+    pub date: Option<DateTime<FixedOffset>>,
+}
+
+/// What the schedule kept out of this build: posts waiting to go live and expired posts
+#[derive(Debug, Default)]
+pub struct ScheduleReport {
+    /// Soonest first
+    pub scheduled: Vec<HeldBackPost>,
+    pub expired: Vec<HeldBackPost>,
+}
+
+impl ScheduleReport {
+    #[must_use]
+    pub fn from_articles(articles: &[Article]) -> Self {
+        let mut scheduled: Vec<HeldBackPost> = articles
+            .iter()
+            .flat_map(|a| &a.scheduled)
+            .map(|post| HeldBackPost {
+                title: post.title.clone(),
+                url: post.url.clone(),
+                date: publish_date(post),
+            })
+            .collect();
+        scheduled.sort_by_key(|p| p.date);
+
+        let expired = articles
+            .iter()
+            .flat_map(|a| &a.expired)
+            .map(|post| HeldBackPost {
+                title: post.title.clone(),
+                url: post.url.clone(),
+                date: post.expiry_date.as_deref().and_then(parse_date),
+            })
+            .collect();
+
+        Self { scheduled, expired }
+    }
+
+    pub fn print(&self) {
+        if self.scheduled.is_empty() && self.expired.is_empty() {
+            return;
+        }
+
+        println!("│  🗓️ Publishing schedule");
+        if let Some(next) = self.scheduled.first() {
+            println!(
+                "│    ⏭️ Next post goes live {}: {} ({})",
+                format_date(next.date),
+                next.title,
+                next.url
+            );
+        }
+        for post in &self.scheduled {
+            println!(
+                "│    ⏳ {}  {} ({})",
+                format_date(post.date),
+                post.title,
+                post.url
+            );
+        }
+        for post in &self.expired {
+            println!(
+                "│    ⌛ Expired {}  {} ({})",
+                format_date(post.date),
+                post.title,
+                post.url
+            );
+        }
+        if !self.scheduled.is_empty() {
+            println!(
+                "│    ℹ️ {} scheduled post(s) held back, run `build --future` to publish them now",
+                self.scheduled.len()
+            );
+        }
+    }
+}
+
+fn format_date(date: Option<DateTime<FixedOffset>>) -> String {
+    date.map_or_else(
+        || "unknown date".to_string(),
+        |d| d.format("%Y-%m-%d %H:%M %:z").to_string(),
+    )
+}
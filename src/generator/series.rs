@@ -0,0 +1,156 @@
+use serde_derive::Serialize;
+use std::collections::BTreeMap;
+
+use crate::app::AppConfig;
+use crate::blog::Article;
+use crate::posts::Post;
+use crate::shared::url::{localized_path, slugify};
+
+use super::site_builder::page_output_path;
+use super::sitemap::output_path_to_url;
+
+/// Link to another post, with the URL already localised for the page's language
+#[derive(Debug, Clone, Serialize)]
+pub struct PostLink {
+    pub title: String,
+    pub url: String,
+}
+
+/// One part of a series as seen from a post of that series
+#[derive(Debug, Clone, Serialize)]
+pub struct SeriesPart {
+    /// Position in the series, starting at 1
+    pub part: usize,
+    pub title: String,
+    pub url: String,
+    pub current: bool,
+}
+
+/// The `series` object given to `post.html`
+#[derive(Debug, Clone, Serialize)]
+pub struct SeriesNav {
+    pub name: String,
+    pub slug: String,
+    /// Series landing page
+    pub url: String,
+    /// Position of the current post, starting at 1
+    pub index: usize,
+    pub total: usize,
+    pub parts: Vec<SeriesPart>,
+    pub prev: Option<SeriesPart>,
+    pub next: Option<SeriesPart>,
+}
+
+/// Posts sharing a `series` frontmatter value, in reading order
+#[derive(Debug, Clone, Serialize)]
+pub struct Series {
+    pub name: String,
+    pub slug: String,
+    pub url: String,
+    pub posts: Vec<Post>,
+}
+
+impl Series {
+    /// Group the posts of a language's articles into series, keyed by slug
+    #[must_use]
+    pub fn build_all(
+        app: &AppConfig,
+        articles: &[&Article],
+        language_code: &str,
+    ) -> BTreeMap<String, Self> {
+        let mut series: BTreeMap<String, Self> = BTreeMap::new();
+
+        for post in articles.iter().flat_map(|a| a.posts()) {
+            let Some(name) = post.series.as_deref().map(str::trim) else {
+                continue;
+            };
+            let slug = slugify(name);
+            if slug.is_empty() {
+                continue;
+            }
+            series
+                .entry(slug.clone())
+                .or_insert_with(|| Self {
+                    name: name.to_string(),
+                    url: output_path_to_url(&page_output_path(
+                        app,
+                        language_code,
+                        &format!("series/{slug}"),
+                    )),
+                    slug,
+                    posts: Vec::new(),
+                })
+                .posts
+                .push(post.clone());
+        }
+
+        // Explicit `series_part` first, then oldest first
+        for s in series.values_mut() {
+            s.posts.sort_by(|a, b| {
+                a.series_part
+                    .unwrap_or(u32::MAX)
+                    .cmp(&b.series_part.unwrap_or(u32::MAX))
+                    .then(a.year.cmp(&b.year))
+                    .then(a.month.cmp(&b.month))
+                    .then(a.day.cmp(&b.day))
+                    .then_with(|| a.title.cmp(&b.title))
+            });
+        }
+
+        series
+    }
+
+    /// Navigation for `post` within this series
+    #[must_use]
+    pub fn nav_for(&self, post: &Post, app: &AppConfig, language_code: &str) -> Option<SeriesNav> {
+        let position = self.posts.iter().position(|p| p.url == post.url)?;
+        let default_language = app.languages.default_language.as_str();
+
+        let parts: Vec<SeriesPart> = self
+            .posts
+            .iter()
+            .enumerate()
+            .map(|(i, p)| SeriesPart {
+                part: i + 1,
+                title: p.title.clone(),
+                url: localized_path(default_language, language_code, &p.url),
+                current: i == position,
+            })
+            .collect();
+
+        Some(SeriesNav {
+            name: self.name.clone(),
+            slug: self.slug.clone(),
+            url: self.url.clone(),
+            index: position + 1,
+            total: parts.len(),
+            prev: position.checked_sub(1).map(|i| parts[i].clone()),
+            next: parts.get(position + 1).cloned(),
+            parts,
+        })
+    }
+}
+
+/// Previous (older) and next (newer) post by date within the post's section
+#[must_use]
+pub fn date_neighbours(
+    article: &Article,
+    post: &Post,
+    app: &AppConfig,
+    language_code: &str,
+) -> (Option<PostLink>, Option<PostLink>) {
+    let default_language = app.languages.default_language.as_str();
+    let link = |p: &Post| PostLink {
+        title: p.title.clone(),
+        url: localized_path(default_language, language_code, &p.url),
+    };
+
+    // Section posts are ordered newest first
+    let posts = article.posts();
+    let Some(position) = posts.iter().position(|p| p.url == post.url) else {
+        return (None, None);
+    };
+    let older = posts.get(position + 1).map(link);
+    let newer = position.checked_sub(1).map(|i| link(&posts[i]));
+    (older, newer)
+}
@@ -6,7 +6,11 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use crate::shared::handlebars::{create_hbs_options, register_all_templates_and_helpers};
+use crate::shared::pages;
+use crate::shared::params::{self, Params};
+use crate::shared::permalink;
 use crate::shared::read_metadata_yml::{self, GenericYmlInfo};
+use crate::shared::url::localized_path;
 use crate::validation::{FileValidator, ValidationConfig};
 use crate::{
     app::{read_config, AppConfig},
@@ -16,8 +20,22 @@ use crate::{
 };
 
 use super::{
-    asset_manager::AssetManager, content_parser::ContentParser, output_writer::OutputWriter,
+    asset_manager::AssetManager,
+    authors::{Author, AuthorRegistry},
+    check,
+    content_parser::ContentParser,
+    data::SiteData,
+    feed::{FeedGenerator, FeedLink},
+    git_info,
+    output_writer::OutputWriter,
     page_processor::PageProcessor,
+    paginator::{self, Paginator},
+    redirects::Redirects,
+    schedule::{BuildOptions, ScheduleReport},
+    series::{PostLink, SeriesNav},
+    sitemap::{lists_drafts, SitemapBuilder},
+    taxonomy::{Taxonomy, TaxonomyTerm},
+    translation_links::{self, Alternate},
 };
 
 pub struct Generator<'a> {
@@ -26,7 +44,9 @@ pub struct Generator<'a> {
     out_directory: PathBuf,
     app: AppConfig,
     all_file_yml: Vec<GenericYmlInfo>,
-    validator: FileValidator,
+    options: BuildOptions,
+    authors: AuthorRegistry,
+    data: SiteData,
 }
 
 #[derive(Debug, Serialize)]
@@ -44,6 +64,14 @@ pub(crate) struct ReleasePost {
     pub(crate) url: String,
 }
 
+/// The page, or the post, being rendered
+#[derive(Debug, Default, Serialize)]
+pub struct Page {
+    pub params: Params,
+    /// Rendered body of a markdown page
+    pub content: Option<String>,
+}
+
 #[derive(Serialize)]
 pub struct Data<'a> {
     pub lang: String,
@@ -67,6 +95,23 @@ pub struct Data<'a> {
     pub date_published: Option<String>,
     pub category: Option<String>,
     pub translations: Option<HashMap<String, String>>,
+    pub feeds: Vec<FeedLink>,
+    pub site: Value,
+    pub taxonomy: Option<&'a Taxonomy<'a>>,
+    pub term: Option<&'a TaxonomyTerm<'a>>,
+    pub pagination: Option<Paginator>,
+    pub series: Option<SeriesNav>,
+    pub prev_post: Option<PostLink>,
+    pub next_post: Option<PostLink>,
+    /// "Read next" suggestions for a post page, URLs localised
+    pub related_posts: Option<Vec<Post>>,
+    /// Registered authors of a post, or every author on `/authors/`
+    pub authors: Option<Vec<Author<'a>>>,
+    /// The author of a profile page (`/authors/<id>/`)
+    pub author: Option<Author<'a>>,
+    /// hreflang links to the other language versions of a post
+    pub alternates: Vec<Alternate>,
+    pub page: Page,
     pub app_name: String,
     pub app_author: String,
     pub app_version: String,
@@ -97,6 +142,40 @@ pub struct Data<'a> {
     pub google_adsense_client: String,
 }
 
+/// Output path (relative to the build directory) of a page such as `about` or `tags/seo`
+#[must_use]
+pub fn page_output_path(app: &AppConfig, language_code: &str, page_name: &str) -> String {
+    let use_directory = app
+        .paths
+        .as_ref()
+        .and_then(|p| p.use_directory_structure)
+        .unwrap_or(true);
+
+    // Special handling for index page - always stays as file
+    let default_lang = app.languages.default_language.as_str();
+    if page_name == "index" {
+        if language_code == default_lang {
+            "index.html".to_string()
+        } else {
+            format!("{language_code}/index.html")
+        }
+    } else if use_directory {
+        // Directory structure: /about/index.html
+        if language_code == default_lang {
+            format!("{page_name}/index.html")
+        } else {
+            format!("{language_code}/{page_name}/index.html")
+        }
+    } else {
+        // Direct file: /about.html
+        if language_code == default_lang {
+            format!("{page_name}.html")
+        } else {
+            format!("{language_code}/{page_name}.html")
+        }
+    }
+}
+
 #[must_use]
 pub fn get_locale_from_lang(lang_code: &str, app: &AppConfig) -> String {
     if let Some(locales) = &app.locales {
@@ -126,6 +205,19 @@ impl Generator<'_> {
     pub fn new(
         out_directory: impl AsRef<Path>,
         posts_directory: impl AsRef<Path>,
+    ) -> eyre::Result<Self> {
+        Self::with_options(out_directory, posts_directory, &BuildOptions::default())
+    }
+
+    /// Creates a generator for a build with command line options such as `--future`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directories cannot be accessed or if there are file system issues
+    pub fn with_options(
+        out_directory: impl AsRef<Path>,
+        posts_directory: impl AsRef<Path>,
+        options: &BuildOptions,
     ) -> eyre::Result<Self> {
         let mut handlebars = Handlebars::new();
         // handlebars.set_strict_mode(true); // Disabled due to empty string root variable for default language
@@ -170,19 +262,49 @@ impl Generator<'_> {
             .and_then(|p| p.contents_dir.as_ref())
             .cloned()
             .unwrap_or_else(|| "contents".to_string());
-        let all_file_yml = read_metadata_yml::scan_yml_files_in_directory(&contents_dir);
+        // Initialize validator from [validation] in app.toml
+        let validator = FileValidator::new(ValidationConfig::from_app(&app));
+
+        // Strict builds check every content file first and stop on any error
+        if validator.config().strict {
+            let check = check::check_contents(&app, posts_directory.as_ref(), &validator);
+            if !check.problems.is_empty() {
+                println!("│  🔍 Checking content...");
+                check.print();
+            }
+            if check.has_errors() {
+                return Err(eyre::eyre!(
+                    "{} content errors, see above (run `genwebblog check` to list them without building)",
+                    check.errors()
+                ));
+            }
+        }
+
+        // YAML pages, then markdown pages
+        let mut all_file_yml = read_metadata_yml::scan_yml_files_in_directory(&contents_dir);
+        all_file_yml.extend(pages::scan(Path::new(&contents_dir), &app)?);
+        for yml_info in &all_file_yml {
+            params::validate(&yml_info.params, &app).map_err(|e| {
+                eyre::eyre!("Invalid params in page \"{}\": {e}", yml_info.page_name)
+            })?;
+        }
 
-        // Initialize validator with default config
-        let validation_config = ValidationConfig::default();
-        let validator = FileValidator::new(validation_config);
+        let mut articles = load(posts_directory.as_ref(), &app, options)?;
+        let authors = AuthorRegistry::load(&app)?;
+        authors.resolve_articles(&mut articles, &app);
+        translation_links::link_translations(&mut articles, &app);
+        git_info::apply(&mut articles, &app);
+        let data = SiteData::load(&app)?;
 
         Ok(Generator {
             handlebars,
-            articles: load(posts_directory.as_ref(), &app)?,
+            articles,
             out_directory: out_directory.as_ref().to_path_buf(),
             app,
             all_file_yml,
-            validator,
+            options: options.clone(),
+            authors,
+            data,
         })
     }
 
@@ -194,15 +316,21 @@ impl Generator<'_> {
     pub fn generate(&self) -> eyre::Result<()> {
         println!("│  🏗️ Starting site generation...");
 
-        // Run validation first
-        self.run_validation()?;
+        ScheduleReport::from_articles(&self.articles).print();
 
         let asset_manager = AssetManager::new(&self.out_directory);
         let mut output_writer = OutputWriter::new(&self.out_directory);
         let content_parser = ContentParser::new(&self.app);
-        let page_processor = PageProcessor::new(&self.handlebars, &self.app, &self.articles);
+        let page_processor = PageProcessor::new(
+            &self.handlebars,
+            &self.app,
+            &self.articles,
+            &self.authors,
+            &self.data,
+        );
+        let mut sitemap = SitemapBuilder::new(&self.app);
 
-    asset_manager.create_build_directories()?;
+        asset_manager.create_build_directories()?;
 
         // Generate pages for each language
         for lang_code in &self.app.languages.installed_languages {
@@ -211,32 +339,42 @@ impl Generator<'_> {
                 &page_processor,
                 &mut output_writer,
                 &content_parser,
+                &mut sitemap,
             )?;
         }
 
-    // Generate final sitemap combining all languages
-    self.generate_final_sitemap(&content_parser, &output_writer)?;
+        // Meta-refresh stubs for post aliases and [redirects]; fails on a collision with a real page
+        let redirects = Redirects::collect(&self.app, &self.articles, self.options.drafts)?;
+        redirects.write_stubs(&self.app, &output_writer)?;
+
+        // Generate sitemap index and per-language sitemaps for every written page
+        sitemap.write(&output_writer)?;
 
-    // --- CSS & asset pipeline ---
-    // 1) Generate Tailwind bundle and write to public system styles
-    let css_bundle = tailwind::process_tailwind_files();
-    let tailwind_path = "public/_system_/styles/tailwind.css";
-    tailwind::save_css_to_file(&css_bundle, tailwind_path);
+        // --- CSS & asset pipeline ---
+        // 1) Generate Tailwind bundle and write to public system styles
+        let css_bundle = tailwind::process_tailwind_files();
+        let tailwind_path = "public/_system_/styles/tailwind.css";
+        tailwind::save_css_to_file(&css_bundle, tailwind_path);
 
-    // 2) Generate robots and compile SCSS sources so their CSS are available
-    crate::shared::robots::generate_robots_txt(&self.app)?;
-    crate::shared::css::compile_sass("app")?;
-    crate::shared::css::compile_sass("noscript")?;
-    crate::shared::css::compile_sass("fonts")?;
+        // 2) Generate robots and compile SCSS sources so their CSS are available
+        crate::shared::robots::generate_robots_txt(&self.app)?;
+        crate::shared::css::compile_sass("app")?;
+        crate::shared::css::compile_sass("noscript")?;
+        crate::shared::css::compile_sass("fonts")?;
 
-    // 3) Concatenate vendor CSS in order: fonts, app, tailwind
-    crate::shared::css::concat_vendor_css(vec!["fonts", "app", "tailwind"])?;
+        // 3) Concatenate vendor CSS in order: fonts, app, tailwind
+        crate::shared::css::concat_vendor_css(vec!["fonts", "app", "tailwind"])?;
 
-    // 4) Copy static files into build (AssetManager will copy only vendor.css and noscript.css from styles)
-    asset_manager.copy_static_assets()?;
+        // 4) Copy static files into build (AssetManager will copy only vendor.css and noscript.css from styles),
+        //    then the files of page bundles next to their posts
+        asset_manager.copy_static_assets()?;
+        asset_manager.copy_bundle_assets(&self.articles)?;
 
-    println!("│  ✅ Site generation completed!");
-    Ok(())
+        // 5) Add the generated redirects to the copied public/_redirects
+        redirects.write_redirects_file(&output_writer)?;
+
+        println!("│  ✅ Site generation completed!");
+        Ok(())
     }
 
     fn generate_pages_for_language(
@@ -245,6 +383,7 @@ impl Generator<'_> {
         page_processor: &PageProcessor,
         output_writer: &mut OutputWriter,
         content_parser: &ContentParser,
+        sitemap: &mut SitemapBuilder,
     ) -> eyre::Result<()> {
         println!("│    🌍 Generating pages for language: {language_code}");
 
@@ -270,6 +409,13 @@ impl Generator<'_> {
                 }
             }
 
+            // Draft pages and sections are only rendered by preview builds (`--drafts`)
+            let draft_page = yml_info.draft == Some(true);
+            if draft_page && !self.options.drafts {
+                println!("│    📝 Draft page: {}", yml_info.page_name);
+                continue;
+            }
+
             // Validate YML content using ContentParser
             if let Err(e) = content_parser.validate_yml_content(yml_info) {
                 eprintln!(
@@ -296,100 +442,105 @@ impl Generator<'_> {
                 // Prepare a mutable translations map so we can inject docs links html for docs page
                 let mut translations_for_render = translations.clone();
 
-                // If we're rendering the docs index, build an HTML list of links from contents/<lang>/docs/
+                // If we're rendering the docs index, build an HTML list of links to the docs section's posts
                 if yml_info.page_name == "docs" {
-                    use std::path::Path;
-                    let contents_dir = self
-                        .app
-                        .paths
-                        .as_ref()
-                        .and_then(|p| p.contents_dir.as_ref())
-                        .cloned()
-                        .unwrap_or_else(|| "contents".to_string());
-
                     let default_lang = self.app.languages.default_language.as_str();
-                    // For default language, docs are in contents/docs; for others, contents/<lang>/docs
-                    let docs_dir = if language_code == default_lang {
-                        format!("{contents_dir}/docs")
-                    } else {
-                        format!("{contents_dir}/{language_code}/docs")
-                    };
-
-                    if Path::new(&docs_dir).exists() {
-                        let mut items: Vec<String> = Vec::new();
-                        for entry in std::fs::read_dir(&docs_dir)? {
-                            let path = entry?.path();
-                            if path.extension().and_then(|e| e.to_str()) == Some("md") {
-                                // derive slug from filename (posts format YYYY-MM-DD-slug.md)
-                                let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
-                                let slug = stem.splitn(4, '-').last().unwrap_or(stem);
-
-                                // read title from frontmatter if available
-                                let mut title = slug.to_string();
-                                if let Ok(content) = crate::shared::fs::read_file_to_string(&path) {
-                                    if content.len() > 5 && content.starts_with("---") {
-                                        if let Some(pos) = content[3..].find("---") {
-                                            let yaml = &content[..pos + 3];
-                                            if let Ok(doc) =
-                                                serde_yaml::from_str::<serde_yaml::Value>(yaml)
-                                            {
-                                                if let Some(t) =
-                                                    doc.get("title").and_then(|v| v.as_str())
-                                                {
-                                                    title = t.to_string();
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-
-                                let url = if language_code == default_lang {
-                                    format!("/docs/{slug}.html")
-                                } else {
-                                    format!("/{language_code}/docs/{slug}.html")
-                                };
-
-                                items.push(format!(
-                                    r#"<li><a href="{}">{}</a></li>"#,
-                                    url,
-                                    html_escape::encode_text(&title)
-                                ));
-                            }
-                        }
-
-                        if !items.is_empty() {
-                            let list_html = format!("<ul>{}</ul>", items.join("\n"));
-                            translations_for_render
-                                .insert("docs_links_html".to_string(), list_html);
-                        }
+                    let items: Vec<String> = language_articles
+                        .iter()
+                        .filter(|a| a.prefix() == Path::new("docs"))
+                        .flat_map(|a| a.posts().iter().rev())
+                        .map(|post| {
+                            format!(
+                                r#"<li><a href="{}">{}</a></li>"#,
+                                localized_path(default_lang, language_code, &post.url),
+                                html_escape::encode_text(&post.title)
+                            )
+                        })
+                        .collect();
+
+                    if !items.is_empty() {
+                        let list_html = format!("<ul>{}</ul>", items.join("\n"));
+                        translations_for_render.insert("docs_links_html".to_string(), list_html);
                     }
                 }
 
-                let content = if yml_info.page_name == "index" {
+                let lastmod = yml_info
+                    .date_modified
+                    .as_deref()
+                    .or(yml_info.date_published.as_deref());
+
+                if yml_info.page_name == "index" {
                     // Special processing for index page
                     let articles_for_listing =
                         content_parser.parse_articles_for_listing(&self.articles, language_code);
-                    page_processor.process_index_page(
-                        article,
-                        yml_info,
+                    let post_count = language_articles.iter().map(|a| a.posts().len()).sum();
+                    let pagers = Paginator::paginate(
+                        &self.app,
                         language_code,
-                        &translations_for_render,
-                        articles_for_listing,
-                        &language_articles, // Pass all articles
-                    )?
+                        "index",
+                        post_count,
+                        paginator::page_size(&self.app, yml_info.paginate),
+                    );
+                    self.write_listing_pages(
+                        language_code,
+                        &pagers,
+                        output_writer,
+                        (!draft_page).then_some(&mut *sitemap),
+                        lastmod,
+                        |pager| {
+                            page_processor.process_index_page(
+                                article,
+                                yml_info,
+                                language_code,
+                                &translations_for_render,
+                                articles_for_listing.clone(),
+                                &language_articles, // Pass all articles
+                                Some(pager),
+                            )
+                        },
+                    )?;
+                } else if article.prefix().to_string_lossy() == yml_info.page_name {
+                    // Section page listing the posts of its folder
+                    let pagers = Paginator::paginate(
+                        &self.app,
+                        language_code,
+                        &yml_info.page_name,
+                        article.posts().len(),
+                        paginator::page_size(&self.app, yml_info.paginate.or(article.paginate)),
+                    );
+                    self.write_listing_pages(
+                        language_code,
+                        &pagers,
+                        output_writer,
+                        (!draft_page).then_some(&mut *sitemap),
+                        lastmod,
+                        |pager| {
+                            page_processor.process_article_page(
+                                article,
+                                yml_info,
+                                language_code,
+                                &translations_for_render,
+                                Some(pager),
+                            )
+                        },
+                    )?;
                 } else {
                     // Regular page processing
-                    page_processor.process_article_page(
+                    let content = page_processor.process_article_page(
                         article,
                         yml_info,
                         language_code,
                         &translations_for_render,
-                    )?
-                };
-
-                let output_path =
-                    self.get_output_path_for_language(language_code, &yml_info.page_name);
-                output_writer.write_html_file(&output_path, &content)?;
+                        None,
+                    )?;
+
+                    let output_path =
+                        self.get_output_path_for_language(language_code, &yml_info.page_name);
+                    output_writer.write_html_file(&output_path, &content)?;
+                    if !draft_page {
+                        sitemap.add_output(language_code, &output_path, lastmod);
+                    }
+                }
             }
         }
 
@@ -403,6 +554,7 @@ impl Generator<'_> {
                 output_writer,
                 &translations,
                 &mut generated_posts,
+                sitemap,
             )?;
         }
 
@@ -414,15 +566,51 @@ impl Generator<'_> {
                 page_processor,
                 output_writer,
                 &translations,
+                sitemap,
             )?;
         }
 
-        // Generate sitemap
-        self.generate_sitemap(
+        // Generate taxonomy index and term listing pages (/tags/, /tags/<tag>/, ...)
+        if let Some(first_article) = language_articles.first() {
+            self.generate_taxonomy_pages(
+                first_article,
+                language_code,
+                page_processor,
+                output_writer,
+                &translations,
+                sitemap,
+            )?;
+        }
+
+        // Generate series landing pages (/series/<slug>/)
+        if let Some(first_article) = language_articles.first() {
+            self.generate_series_pages(
+                first_article,
+                language_code,
+                page_processor,
+                output_writer,
+                &translations,
+                sitemap,
+            )?;
+        }
+
+        // Generate author index and profile pages (/authors/, /authors/<id>/)
+        if let Some(first_article) = language_articles.first() {
+            self.generate_author_pages(
+                first_article,
+                language_code,
+                page_processor,
+                output_writer,
+                &translations,
+                sitemap,
+            )?;
+        }
+
+        // Generate RSS, Atom and JSON feeds (site-wide, per section and per tag)
+        FeedGenerator::new(&self.app).write_for_language(
             &language_articles,
             language_code,
             output_writer,
-            content_parser,
         )?;
 
         // Generate releases.json for this language
@@ -433,38 +621,6 @@ impl Generator<'_> {
         Ok(())
     }
 
-    fn generate_sitemap(
-        &self,
-        articles: &[&Article],
-        language_code: &str,
-        output_writer: &mut OutputWriter,
-        content_parser: &ContentParser,
-    ) -> eyre::Result<()> {
-        println!("│    🗺️ Generating sitemap for language: {language_code}");
-
-        // Create sitemap entries for this specific language
-        let sitemap_entries = content_parser.create_sitemap_entries_for_language(
-            articles,
-            &self.app.app_info.app_domain,
-            language_code,
-        );
-        let sitemap_json = serde_json::Value::Array(sitemap_entries.clone());
-
-        let sitemap_path = if language_code == self.app.languages.default_language {
-            "sitemap.json".to_string()
-        } else {
-            format!("{language_code}/sitemap.json")
-        };
-
-        output_writer.write_json_file(&sitemap_path, &sitemap_json)?;
-        println!(
-            "│    ✅ Generated sitemap: {} with {} entries",
-            sitemap_path,
-            sitemap_entries.len()
-        );
-        Ok(())
-    }
-
     fn generate_releases_json(
         &self,
         articles: &[&Article],
@@ -552,39 +708,7 @@ impl Generator<'_> {
         }
     }
 
-    fn generate_final_sitemap(
-        &self,
-        content_parser: &ContentParser,
-        output_writer: &OutputWriter,
-    ) -> eyre::Result<()> {
-        println!("│    🗺️ Generating final sitemap...");
-
-        // Create sitemap entries for all articles and languages
-        let mut all_sitemap_entries = Vec::new();
-
-        for language_code in &self.app.languages.installed_languages {
-            let language_articles =
-                content_parser.filter_articles_by_language(&self.articles, language_code);
-            let entries = content_parser.create_sitemap_entries_for_language(
-                &language_articles,
-                &self.app.app_info.app_domain,
-                language_code,
-            );
-            all_sitemap_entries.extend(entries);
-        }
-
-        // Convert to JSON and save
-        let sitemap_json = serde_json::Value::Array(all_sitemap_entries);
-        output_writer.write_json_file("sitemap.json", &sitemap_json)?;
-
-        println!(
-            "│    ✅ Generated final sitemap with {} entries",
-            sitemap_json.as_array().map_or(0, std::vec::Vec::len)
-        );
-        Ok(())
-    }
-
-    #[allow(clippy::unused_self)]
+    #[allow(clippy::too_many_arguments)]
     fn generate_posts_for_article(
         &self,
         article: &Article,
@@ -593,16 +717,17 @@ impl Generator<'_> {
         output_writer: &OutputWriter,
         translations: &std::collections::HashMap<String, String>,
         generated_posts: &mut std::collections::HashSet<String>,
+        sitemap: &mut SitemapBuilder,
     ) -> eyre::Result<()> {
         // Generate individual post pages for this article
         for post in article.posts() {
-            if post.draft {
+            if post.draft && !self.options.drafts {
                 println!("│    📝 Draft: {} ({})", post.title, post.url);
                 continue;
             }
 
             // Check if this post was already generated
-            let post_key = format!("{}:{}", language_code, post.filename);
+            let post_key = format!("{}:{}", language_code, post.url);
             if generated_posts.contains(&post_key) {
                 continue;
             }
@@ -610,10 +735,19 @@ impl Generator<'_> {
             let post_content =
                 page_processor.process_post_page(article, post, language_code, translations)?;
 
-            // Determine output path for post (include article prefix for subdirectory)
-            let post_output_path =
-                self.get_post_output_path_for_language(language_code, post, article);
+            // Output path follows the post permalink
+            let post_output_path = self.get_post_output_path_for_language(language_code, post);
             output_writer.write_html_file(&post_output_path, &post_content)?;
+            if !post.draft {
+                sitemap.add_output_with_key(
+                    language_code,
+                    &post_output_path,
+                    post.date_modified
+                        .as_deref()
+                        .or(Some(post.updated.as_str())),
+                    post.translation_key.as_deref(),
+                );
+            }
 
             // Mark this post as generated
             generated_posts.insert(post_key);
@@ -630,13 +764,14 @@ impl Generator<'_> {
         page_processor: &PageProcessor,
         output_writer: &OutputWriter,
         _translations: &std::collections::HashMap<String, String>,
+        sitemap: &mut SitemapBuilder,
     ) -> eyre::Result<()> {
         // Read title and description from articles.yml
         let articles_yaml_path = std::path::Path::new("contents").join("articles.yml");
         // Load translations for this language
         let translations = Self::load_translations(language_code);
 
-        let (title, description) = if articles_yaml_path.exists() {
+        let (title, description, paginate, params) = if articles_yaml_path.exists() {
             let yaml_content = crate::shared::fs::read_file_to_string(&articles_yaml_path)?;
             let yaml_data: serde_yaml::Value = serde_yaml::from_str(&yaml_content)?;
 
@@ -660,7 +795,21 @@ impl Generator<'_> {
                 })
                 .to_string();
 
-            (title, description)
+            let paginate = yaml_data
+                .get("paginate")
+                .and_then(serde_yaml::Value::as_u64)
+                .and_then(|n| usize::try_from(n).ok());
+
+            let params: Params = yaml_data
+                .get("params")
+                .and_then(|v| serde_json::to_value(v).ok())
+                .and_then(|v| v.as_object().cloned())
+                .unwrap_or_default();
+            params::validate(&params, &self.app).map_err(|e| {
+                eyre::eyre!("Invalid params in {}: {e}", articles_yaml_path.display())
+            })?;
+
+            (title, description, paginate, params)
         } else {
             let title = translations
                 .get("articles_title")
@@ -682,7 +831,7 @@ impl Generator<'_> {
                         "รวมบทความและเนื้อหาที่น่าสนใจ".to_string()
                     }
                 });
-            (title, description)
+            (title, description, None, Params::new())
         };
 
         // Use the first article for date information (or current date if none)
@@ -720,7 +869,7 @@ impl Generator<'_> {
             image: None,
             draft: None,
             date_published: Some(date_published.clone()),
-            date_modified: Some(date_published),
+            date_modified: Some(date_published.clone()),
             lang: Some(language_code.to_string()),
             layout: Some("articles".to_string()),
             category: None,
@@ -728,162 +877,247 @@ impl Generator<'_> {
             author: None,
             author_url: None,
             author_email: None,
+            paginate,
+            params,
+            content: None,
         };
 
         // Use articles listing processing (should create one from existing article processing)
-        let articles_content = if let Some(first_article) = articles.first() {
-            page_processor.process_article_page(
-                first_article,
-                &articles_yml,
-                language_code,
-                &translations,
-            )?
-        } else {
+        let Some(first_article) = articles.first() else {
             return Ok(()); // Skip if no articles
         };
 
-        // Determine output path for articles listing
-        let default_lang = self.app.languages.default_language.as_str();
-        let articles_output_path = if language_code == default_lang {
-            "articles/index.html".to_string()
-        } else {
-            format!("{language_code}/articles/index.html")
-        };
-
-        output_writer.write_html_file(&articles_output_path, &articles_content)?;
+        let post_count = articles.iter().map(|a| a.posts().len()).sum();
+        let pagers = Paginator::paginate(
+            &self.app,
+            language_code,
+            "articles",
+            post_count,
+            paginator::page_size(&self.app, paginate),
+        );
+        self.write_listing_pages(
+            language_code,
+            &pagers,
+            output_writer,
+            Some(sitemap),
+            Some(&date_published),
+            |pager| {
+                page_processor.process_article_page(
+                    first_article,
+                    &articles_yml,
+                    language_code,
+                    &translations,
+                    Some(pager),
+                )
+            },
+        )
+    }
 
-        Ok(())
+    /// Output path of a post, derived from its permalink (`seo/post.html`, `en/seo/post/index.html`)
+    fn get_post_output_path_for_language(&self, language_code: &str, post: &Post) -> String {
+        let path = permalink::output_path(&post.url);
+        if language_code == self.app.languages.default_language {
+            path
+        } else {
+            format!("{language_code}/{path}")
+        }
     }
 
-    fn get_post_output_path_for_language(
+    fn generate_taxonomy_pages(
         &self,
-        language_code: &str,
-        post: &Post,
         article: &Article,
-    ) -> String {
-        // Include article prefix in post path to handle subdirectories
-        let mut filename = std::path::PathBuf::from(&post.filename);
-        filename.set_extension("html");
-
-        // Get article prefix relative path (e.g., "web-optimization" for subdirectory)
-        let article_prefix = article.prefix();
-
-        let default_lang = self.app.languages.default_language.as_str();
+        language_code: &str,
+        page_processor: &PageProcessor,
+        output_writer: &OutputWriter,
+        translations: &HashMap<String, String>,
+        sitemap: &mut SitemapBuilder,
+    ) -> eyre::Result<()> {
+        for taxonomy in page_processor.taxonomies_for_language(language_code) {
+            if taxonomy.terms.is_empty() {
+                continue;
+            }
 
-        if language_code == default_lang {
-            if article_prefix.as_os_str().is_empty() {
-                // Root article - place directly in build root
-                filename.to_string_lossy().to_string()
+            if self.handlebars.has_template("taxonomy") {
+                let content = page_processor.process_taxonomy_page(
+                    article,
+                    taxonomy,
+                    None,
+                    language_code,
+                    translations,
+                    None,
+                )?;
+                let output_path = self.get_output_path_for_language(language_code, &taxonomy.name);
+                output_writer.write_html_file(&output_path, &content)?;
+                sitemap.add_output(language_code, &output_path, None);
             } else {
-                // Subdirectory article - place in subdirectory
-                format!(
-                    "{}/{}",
-                    article_prefix.to_string_lossy(),
-                    filename.to_string_lossy()
-                )
+                println!(
+                    "│    ⚠️ Template 'taxonomy' not found, skipping /{}/ index",
+                    taxonomy.name
+                );
             }
-        } else if article_prefix.as_os_str().is_empty() {
-            // Root article - place in language directory
-            format!("{}/{}", language_code, filename.to_string_lossy())
-        } else {
-            // If the article prefix equals the language code (e.g., contents/en/...)
-            // avoid producing duplicated path like `en/en/...` and place under `en/...` instead.
-            let prefix_str = article_prefix.to_string_lossy();
-            if prefix_str == language_code {
-                format!("{}/{}", language_code, filename.to_string_lossy())
-            } else {
-                // Subdirectory article - place in language/subdirectory
-                format!(
-                    "{}/{}/{}",
+
+            if !self.handlebars.has_template("taxonomy_term") {
+                println!(
+                    "│    ⚠️ Template 'taxonomy_term' not found, skipping {} pages",
+                    taxonomy.name
+                );
+                continue;
+            }
+            let per_page = paginator::page_size(&self.app, None);
+            for term in &taxonomy.terms {
+                let pagers = Paginator::paginate(
+                    &self.app,
                     language_code,
-                    article_prefix.to_string_lossy(),
-                    filename.to_string_lossy()
-                )
+                    &format!("{}/{}", taxonomy.name, term.slug),
+                    term.posts.len(),
+                    per_page,
+                );
+                // Like the draft posts it shows, a listing of drafts stays out of the sitemap
+                self.write_listing_pages(
+                    language_code,
+                    &pagers,
+                    output_writer,
+                    (!lists_drafts(&term.posts)).then_some(&mut *sitemap),
+                    term.posts.first().map(|p| p.updated.as_str()),
+                    |pager| {
+                        page_processor.process_taxonomy_page(
+                            article,
+                            taxonomy,
+                            Some(term),
+                            language_code,
+                            translations,
+                            Some(pager),
+                        )
+                    },
+                )?;
             }
         }
+
+        Ok(())
     }
 
-    fn get_output_path_for_language(&self, language_code: &str, page_name: &str) -> String {
-        let use_directory = self
-            .app
-            .paths
-            .as_ref()
-            .and_then(|p| p.use_directory_structure)
-            .unwrap_or(true);
+    fn generate_series_pages(
+        &self,
+        article: &Article,
+        language_code: &str,
+        page_processor: &PageProcessor,
+        output_writer: &OutputWriter,
+        translations: &HashMap<String, String>,
+        sitemap: &mut SitemapBuilder,
+    ) -> eyre::Result<()> {
+        let mut series = page_processor.series_for_language(language_code).peekable();
+        if series.peek().is_none() {
+            return Ok(());
+        }
+        if !self.handlebars.has_template("series") {
+            println!("│    ⚠️ Template 'series' not found, skipping series pages");
+            return Ok(());
+        }
 
-        // Special handling for index page - always stays as file
-        let default_lang = self.app.languages.default_language.as_str();
-        if page_name == "index" {
-            if language_code == default_lang {
-                "index.html".to_string()
-            } else {
-                format!("{language_code}/index.html")
-            }
-        } else if use_directory {
-            // Directory structure: /about/index.html
-            if language_code == default_lang {
-                format!("{page_name}/index.html")
-            } else {
-                format!("{language_code}/{page_name}/index.html")
-            }
-        } else {
-            // Direct file: /about.html
-            if language_code == default_lang {
-                format!("{page_name}.html")
-            } else {
-                format!("{language_code}/{page_name}.html")
+        for series in series {
+            let content =
+                page_processor.process_series_page(article, series, language_code, translations)?;
+            let output_path = self
+                .get_output_path_for_language(language_code, &format!("series/{}", series.slug));
+            output_writer.write_html_file(&output_path, &content)?;
+            if !lists_drafts(&series.posts) {
+                sitemap.add_output(
+                    language_code,
+                    &output_path,
+                    series.posts.iter().map(|p| p.updated.as_str()).max(),
+                );
             }
         }
-    }
 
-    /// Run file validation on contents and public directories
-    fn run_validation(&self) -> eyre::Result<()> {
-        println!("│  🔍 Running file validation...");
+        Ok(())
+    }
 
-        let mut has_errors = false;
+    fn generate_author_pages(
+        &self,
+        article: &Article,
+        language_code: &str,
+        page_processor: &PageProcessor,
+        output_writer: &OutputWriter,
+        translations: &HashMap<String, String>,
+        sitemap: &mut SitemapBuilder,
+    ) -> eyre::Result<()> {
+        let authors = page_processor.authors_for_language(language_code);
+        if authors.is_empty() {
+            return Ok(());
+        }
 
-        // Validate contents directory
-        let contents_dir = self
-            .app
-            .paths
-            .as_ref()
-            .and_then(|p| p.contents_dir.as_ref())
-            .cloned()
-            .unwrap_or_else(|| "contents".to_string());
+        if self.handlebars.has_template("authors") {
+            let content = page_processor.process_author_page(
+                article,
+                None,
+                language_code,
+                translations,
+                None,
+            )?;
+            let output_path = self.get_output_path_for_language(language_code, "authors");
+            output_writer.write_html_file(&output_path, &content)?;
+            sitemap.add_output(language_code, &output_path, None);
+        } else {
+            println!("│    ⚠️ Template 'authors' not found, skipping /authors/ index");
+        }
 
-        if Path::new(&contents_dir).exists() {
-            let contents_summary = self
-                .validator
-                .validate_directory(Path::new(&contents_dir))?;
-            if contents_summary.has_errors() {
-                has_errors = true;
-            }
-            contents_summary.print_summary();
+        if !self.handlebars.has_template("author") {
+            println!("│    ⚠️ Template 'author' not found, skipping author pages");
+            return Ok(());
+        }
+        let per_page = paginator::page_size(&self.app, None);
+        for author in authors {
+            let pagers = Paginator::paginate(
+                &self.app,
+                language_code,
+                &format!("authors/{}", author.id),
+                author.posts.len(),
+                per_page,
+            );
+            self.write_listing_pages(
+                language_code,
+                &pagers,
+                output_writer,
+                (!lists_drafts(&author.posts)).then_some(&mut *sitemap),
+                author.posts.first().map(|p| p.updated.as_str()),
+                |pager| {
+                    page_processor.process_author_page(
+                        article,
+                        Some(author),
+                        language_code,
+                        translations,
+                        Some(pager),
+                    )
+                },
+            )?;
         }
 
-        // Validate public directory
-        let public_dir = self
-            .app
-            .paths
-            .as_ref()
-            .and_then(|p| p.public_dir.as_ref())
-            .cloned()
-            .unwrap_or_else(|| "public".to_string());
+        Ok(())
+    }
 
-        if Path::new(&public_dir).exists() {
-            let public_summary = self.validator.validate_directory(Path::new(&public_dir))?;
-            if public_summary.has_errors() {
-                has_errors = true;
+    /// Render and write every page of a paginated listing (`/articles/`, `/articles/page/2/`, ...).
+    /// Draft previews, and listings showing draft posts, pass no `sitemap` so they stay out of it.
+    fn write_listing_pages(
+        &self,
+        language_code: &str,
+        pagers: &[Paginator],
+        output_writer: &OutputWriter,
+        mut sitemap: Option<&mut SitemapBuilder>,
+        lastmod: Option<&str>,
+        render: impl Fn(&Paginator) -> eyre::Result<String>,
+    ) -> eyre::Result<()> {
+        for pager in pagers {
+            let content = render(pager)?;
+            let output_path = self.get_output_path_for_language(language_code, pager.page_name());
+            output_writer.write_html_file(&output_path, &content)?;
+            if let Some(sitemap) = sitemap.as_deref_mut() {
+                sitemap.add_output(language_code, &output_path, lastmod);
             }
-            // ไม่ต้องแสดง public_summary.print_summary();
         }
-
-        if has_errors {
-            println!("│  ⚠️  Validation completed with errors - continuing with build...");
-        } else {
-            println!("│  ✅ Validation completed successfully!");
-        }
-
         Ok(())
     }
+
+    fn get_output_path_for_language(&self, language_code: &str, page_name: &str) -> String {
+        page_output_path(&self.app, language_code, page_name)
+    }
 }
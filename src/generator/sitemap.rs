@@ -0,0 +1,324 @@
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::app::AppConfig;
+use crate::constants::seo::{DEFAULT_SITEMAP_CHANGEFREQ, DEFAULT_SITEMAP_PRIORITY};
+use crate::posts::Post;
+use crate::shared::url::encode_path;
+use crate::shared::utils::get_string_ref_or_default;
+
+use super::output_writer::OutputWriter;
+
+const SITEMAP_INDEX: &str = "sitemap.xml";
+const HOME_PRIORITY: &str = "1.0";
+
+/// A single `<url>` entry of a language sitemap.
+#[derive(Debug, Clone)]
+pub struct SitemapEntry {
+    pub lang: String,
+    /// Language-independent path used to pair the same page across languages
+    pub key: String,
+    /// Public path of the page, e.g. `/en/docs/getting-started.html`
+    pub path: String,
+    pub lastmod: Option<String>,
+    pub changefreq: String,
+    pub priority: String,
+}
+
+/// Collects every page written during a build and renders spec-compliant
+/// sitemaps: one `urlset` per language plus a sitemap index at `/sitemap.xml`.
+pub struct SitemapBuilder<'a> {
+    app: &'a AppConfig,
+    entries: Vec<SitemapEntry>,
+}
+
+impl<'a> SitemapBuilder<'a> {
+    #[must_use]
+    pub fn new(app: &'a AppConfig) -> Self {
+        Self {
+            app,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Register a page by the path it was written to inside the build directory
+    /// (e.g. `en/about/index.html`). Registering the same page twice keeps the last entry.
+    pub fn add_output(&mut self, language_code: &str, output_path: &str, lastmod: Option<&str>) {
+        self.add_output_with_key(language_code, output_path, lastmod, None);
+    }
+
+    /// Like [`Self::add_output`], pairing the page across languages by `key`
+    /// (a post's `translation_key`) instead of by its path.
+    pub fn add_output_with_key(
+        &mut self,
+        language_code: &str,
+        output_path: &str,
+        lastmod: Option<&str>,
+        key: Option<&str>,
+    ) {
+        let path = output_path_to_url(output_path);
+        let key = match key {
+            Some(key) => format!("translation:{key}"),
+            None => self.language_independent_key(language_code, &path),
+        };
+        let priority = if key == "/" {
+            HOME_PRIORITY.to_string()
+        } else {
+            self.default_priority()
+        };
+
+        let entry = SitemapEntry {
+            lang: language_code.to_string(),
+            key,
+            path,
+            lastmod: lastmod.and_then(normalize_lastmod),
+            changefreq: self.default_changefreq(),
+            priority,
+        };
+
+        if let Some(existing) = self
+            .entries
+            .iter_mut()
+            .find(|e| e.lang == entry.lang && e.path == entry.path)
+        {
+            *existing = entry;
+        } else {
+            self.entries.push(entry);
+        }
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> &[SitemapEntry] {
+        &self.entries
+    }
+
+    /// Path (relative to the build directory) of the urlset for a language.
+    ///
+    /// A sitemap may only list URLs below its own location, so the default
+    /// language (served from `/`) lives at the root as `sitemap-<lang>.xml`.
+    #[must_use]
+    pub fn sitemap_path_for_language(app: &AppConfig, language_code: &str) -> String {
+        if language_code == app.languages.default_language {
+            format!("sitemap-{language_code}.xml")
+        } else {
+            format!("{language_code}/sitemap.xml")
+        }
+    }
+
+    #[must_use]
+    pub fn render_urlset(&self, language_code: &str) -> String {
+        let domain = self.app.app_info.app_domain.trim_end_matches('/');
+
+        // Group alternates by key so every language version points to all the others
+        let mut alternates: BTreeMap<&str, Vec<&SitemapEntry>> = BTreeMap::new();
+        for entry in &self.entries {
+            alternates
+                .entry(entry.key.as_str())
+                .or_default()
+                .push(entry);
+        }
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(
+            "<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\" xmlns:xhtml=\"http://www.w3.org/1999/xhtml\">\n",
+        );
+
+        for entry in self.entries.iter().filter(|e| e.lang == language_code) {
+            xml.push_str("  <url>\n");
+            let _ = writeln!(
+                xml,
+                "    <loc>{}</loc>",
+                html_escape::encode_text(&absolute_url(domain, &entry.path))
+            );
+
+            let versions = alternates
+                .get(entry.key.as_str())
+                .map_or(&[][..], Vec::as_slice);
+            if versions.len() > 1 {
+                for version in versions {
+                    let _ = writeln!(
+                        xml,
+                        "    <xhtml:link rel=\"alternate\" hreflang=\"{}\" href=\"{}\"/>",
+                        html_escape::encode_double_quoted_attribute(&version.lang),
+                        html_escape::encode_double_quoted_attribute(&absolute_url(
+                            domain,
+                            &version.path
+                        ))
+                    );
+                }
+                if let Some(default_version) = versions
+                    .iter()
+                    .find(|v| v.lang == self.app.languages.default_language)
+                {
+                    let _ = writeln!(
+                        xml,
+                        "    <xhtml:link rel=\"alternate\" hreflang=\"x-default\" href=\"{}\"/>",
+                        html_escape::encode_double_quoted_attribute(&absolute_url(
+                            domain,
+                            &default_version.path
+                        ))
+                    );
+                }
+            }
+
+            if let Some(lastmod) = &entry.lastmod {
+                let _ = writeln!(xml, "    <lastmod>{lastmod}</lastmod>");
+            }
+            let _ = writeln!(
+                xml,
+                "    <changefreq>{}</changefreq>",
+                html_escape::encode_text(&entry.changefreq)
+            );
+            let _ = writeln!(
+                xml,
+                "    <priority>{}</priority>",
+                html_escape::encode_text(&entry.priority)
+            );
+            xml.push_str("  </url>\n");
+        }
+
+        xml.push_str("</urlset>\n");
+        xml
+    }
+
+    #[must_use]
+    pub fn render_index(&self) -> String {
+        let domain = self.app.app_info.app_domain.trim_end_matches('/');
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+
+        for language_code in &self.app.languages.installed_languages {
+            let lastmod = self
+                .entries
+                .iter()
+                .filter(|e| &e.lang == language_code)
+                .filter_map(|e| e.lastmod.as_deref())
+                .max();
+
+            let path = format!(
+                "/{}",
+                Self::sitemap_path_for_language(self.app, language_code)
+            );
+            xml.push_str("  <sitemap>\n");
+            let _ = writeln!(
+                xml,
+                "    <loc>{}</loc>",
+                html_escape::encode_text(&absolute_url(domain, &path))
+            );
+            if let Some(lastmod) = lastmod {
+                let _ = writeln!(xml, "    <lastmod>{lastmod}</lastmod>");
+            }
+            xml.push_str("  </sitemap>\n");
+        }
+
+        xml.push_str("</sitemapindex>\n");
+        xml
+    }
+
+    /// Write every language urlset and the sitemap index into the build directory
+    pub fn write(&self, output_writer: &OutputWriter) -> eyre::Result<()> {
+        println!("│    🗺️ Generating sitemaps...");
+
+        for language_code in &self.app.languages.installed_languages {
+            let path = Self::sitemap_path_for_language(self.app, language_code);
+            output_writer.write_text_file(&path, &self.render_urlset(language_code))?;
+        }
+
+        output_writer.write_text_file(SITEMAP_INDEX, &self.render_index())?;
+        println!(
+            "│    ✅ Generated sitemap index with {} URLs",
+            self.entries.len()
+        );
+        Ok(())
+    }
+
+    fn language_independent_key(&self, language_code: &str, path: &str) -> String {
+        if language_code == self.app.languages.default_language {
+            return path.to_string();
+        }
+
+        let lang_prefix = format!("/{language_code}");
+        match path.strip_prefix(&lang_prefix) {
+            Some("") => "/".to_string(),
+            Some(rest) if rest.starts_with('/') => rest.to_string(),
+            _ => path.to_string(),
+        }
+    }
+
+    fn default_changefreq(&self) -> String {
+        get_string_ref_or_default(
+            &self
+                .app
+                .seo
+                .as_ref()
+                .and_then(|s| s.sitemap_changefreq.as_ref()),
+            DEFAULT_SITEMAP_CHANGEFREQ,
+        )
+        .to_string()
+    }
+
+    fn default_priority(&self) -> String {
+        get_string_ref_or_default(
+            &self
+                .app
+                .seo
+                .as_ref()
+                .and_then(|s| s.sitemap_priority.as_ref()),
+            DEFAULT_SITEMAP_PRIORITY,
+        )
+        .to_string()
+    }
+}
+
+/// Convert a build-relative output path into the public URL path it is served from.
+/// `about/index.html` becomes `/about/`, `index.html` becomes `/`.
+#[must_use]
+pub fn output_path_to_url(output_path: &str) -> String {
+    let trimmed = output_path.trim_start_matches('/');
+    if trimmed == "index.html" {
+        return "/".to_string();
+    }
+    match trimmed.strip_suffix("/index.html") {
+        Some(dir) => format!("/{dir}/"),
+        None => format!("/{trimmed}"),
+    }
+}
+
+/// Normalise a frontmatter date into the W3C datetime format required by `<lastmod>`.
+/// Returns `None` when the value cannot be interpreted as a date.
+#[must_use]
+pub fn normalize_lastmod(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+    }
+    if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Some(
+            dt.and_utc()
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        );
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+}
+
+/// Whether a listing shows a draft post, which only happens in `--drafts` previews;
+/// such a listing is kept out of the sitemap.
+#[must_use]
+pub fn lists_drafts<P: Borrow<Post>>(posts: &[P]) -> bool {
+    posts.iter().any(|post| post.borrow().draft)
+}
+
+/// Absolute URL of a site path, percent-encoded as sitemaps require
+fn absolute_url(domain: &str, path: &str) -> String {
+    format!("{domain}{}", encode_path(path))
+}
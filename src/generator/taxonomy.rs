@@ -0,0 +1,161 @@
+use serde_derive::Serialize;
+use std::collections::BTreeMap;
+
+use crate::app::AppConfig;
+use crate::blog::Article;
+use crate::posts::Post;
+use crate::shared::url::slugify;
+
+use super::site_builder::page_output_path;
+use super::sitemap::output_path_to_url;
+
+const DEFAULT_TAXONOMIES: &[&str] = &["tags", "categories"];
+
+/// Names of the taxonomies enabled in `app.toml` (`[taxonomies] names = [...]`)
+#[must_use]
+pub fn configured_taxonomies(app: &AppConfig) -> Vec<String> {
+    match &app.taxonomies {
+        Some(t) if t.enabled == Some(false) => Vec::new(),
+        Some(t) => t.names.clone().unwrap_or_else(default_taxonomies),
+        None => default_taxonomies(),
+    }
+}
+
+fn default_taxonomies() -> Vec<String> {
+    DEFAULT_TAXONOMIES.iter().map(ToString::to_string).collect()
+}
+
+/// Frontmatter key a taxonomy reads its terms from.
+/// `categories` maps onto the singular `category` field; custom taxonomies use their own name.
+#[must_use]
+pub fn taxonomy_field(name: &str) -> &str {
+    match name {
+        "categories" => "category",
+        other => other,
+    }
+}
+
+/// A term of a taxonomy (one tag, one category, ...) with the posts filed under it
+#[derive(Debug, Clone, Serialize)]
+pub struct TaxonomyTerm<'a> {
+    pub name: String,
+    pub slug: String,
+    pub url: String,
+    pub count: usize,
+    /// Newest first
+    #[serde(skip)]
+    pub posts: Vec<&'a Post>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Taxonomy<'a> {
+    pub name: String,
+    pub url: String,
+    pub terms: Vec<TaxonomyTerm<'a>>,
+}
+
+impl<'a> Taxonomy<'a> {
+    /// Group the posts of a language's articles into terms, most used first
+    #[must_use]
+    pub fn build(
+        app: &AppConfig,
+        name: &str,
+        articles: &[&'a Article],
+        language_code: &str,
+    ) -> Self {
+        let mut by_slug: BTreeMap<String, TaxonomyTerm<'a>> = BTreeMap::new();
+
+        for post in articles.iter().flat_map(|a| a.posts()) {
+            for term in post.taxonomies.get(name).into_iter().flatten() {
+                let slug = slugify(term);
+                if slug.is_empty() {
+                    continue;
+                }
+                let entry = by_slug.entry(slug.clone()).or_insert_with(|| TaxonomyTerm {
+                    name: term.clone(),
+                    url: taxonomy_url(app, language_code, &format!("{name}/{slug}")),
+                    slug,
+                    count: 0,
+                    posts: Vec::new(),
+                });
+                if !entry.posts.iter().any(|p| p.url == post.url) {
+                    entry.posts.push(post);
+                    entry.count += 1;
+                }
+            }
+        }
+
+        let mut terms: Vec<TaxonomyTerm> = by_slug.into_values().collect();
+        for term in &mut terms {
+            term.posts.sort_by(|a, b| {
+                b.year
+                    .cmp(&a.year)
+                    .then(b.month.cmp(&a.month))
+                    .then(b.day.cmp(&a.day))
+            });
+        }
+        terms.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+
+        Self {
+            name: name.to_string(),
+            url: taxonomy_url(app, language_code, name),
+            terms,
+        }
+    }
+
+    /// Build every configured taxonomy for a language
+    #[must_use]
+    pub fn build_all(app: &AppConfig, articles: &[&'a Article], language_code: &str) -> Vec<Self> {
+        configured_taxonomies(app)
+            .iter()
+            .map(|name| Self::build(app, name, articles, language_code))
+            .collect()
+    }
+}
+
+fn taxonomy_url(app: &AppConfig, language_code: &str, page_name: &str) -> String {
+    output_path_to_url(&page_output_path(app, language_code, page_name))
+}
+
+/// Collect the terms of every configured taxonomy from a post's frontmatter.
+/// `extra` holds the frontmatter keys `Post` has no dedicated field for.
+#[must_use]
+pub fn terms_from_frontmatter(
+    app: &AppConfig,
+    tags: Option<&Vec<String>>,
+    category: Option<&String>,
+    extra: &BTreeMap<String, serde_yaml::Value>,
+) -> BTreeMap<String, Vec<String>> {
+    let mut taxonomies = BTreeMap::new();
+
+    for name in configured_taxonomies(app) {
+        let terms: Vec<String> = match taxonomy_field(&name) {
+            "tags" => tags.cloned().unwrap_or_default(),
+            "category" => category.cloned().into_iter().collect(),
+            field => match extra.get(field) {
+                Some(serde_yaml::Value::String(s)) => vec![s.clone()],
+                Some(serde_yaml::Value::Number(n)) => vec![n.to_string()],
+                Some(serde_yaml::Value::Sequence(values)) => values
+                    .iter()
+                    .filter_map(|v| match v {
+                        serde_yaml::Value::String(s) => Some(s.clone()),
+                        serde_yaml::Value::Number(n) => Some(n.to_string()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            },
+        };
+
+        let terms: Vec<String> = terms
+            .into_iter()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if !terms.is_empty() {
+            taxonomies.insert(name, terms);
+        }
+    }
+
+    taxonomies
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::app::AppConfig;
+use crate::blog::Article;
+use crate::posts::Post;
+use crate::shared::language::LanguageManager;
+use crate::shared::url::localized_path;
+
+/// One language version of a post, as listed by the language switcher
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PostTranslation {
+    pub lang: String,
+    /// Native name of the language, e.g. `ไทย` or `English`
+    pub name: String,
+    /// Site path of the translation, or of the section page when there is none
+    pub url: String,
+    pub title: String,
+    /// The version being rendered
+    pub current: bool,
+    /// No translation exists and `url` points to the section page instead
+    pub fallback: bool,
+}
+
+/// A `<link rel="alternate" hreflang>` tag of a post page
+#[derive(Debug, Clone, Serialize)]
+pub struct Alternate {
+    pub hreflang: String,
+    pub href: String,
+}
+
+/// Posts are translations of each other when they share a `translation_key`,
+/// or, without one, the same language-independent URL
+#[must_use]
+pub fn translation_group(post: &Post) -> &str {
+    post.translation_key.as_deref().unwrap_or(&post.url)
+}
+
+/// Fill `post.translations` of every published post with one entry per installed language
+pub fn link_translations(articles: &mut [Article], app: &AppConfig) {
+    let default_language = app.languages.default_language.as_str();
+    let names = LanguageManager::new().available_languages;
+
+    // group -> language -> (path, title)
+    let mut groups: HashMap<String, HashMap<String, (String, String)>> = HashMap::new();
+    // (language, section path) -> section title
+    let mut sections: HashMap<(String, String), String> = HashMap::new();
+    for article in articles.iter() {
+        let lang = article_language(article, default_language);
+        sections.insert(
+            (lang.to_string(), section_path(article.prefix())),
+            article.title().to_string(),
+        );
+        for post in article.posts() {
+            groups
+                .entry(translation_group(post).to_string())
+                .or_default()
+                .entry(lang.to_string())
+                .or_insert_with(|| {
+                    (
+                        localized_path(default_language, lang, &post.url),
+                        post.title.clone(),
+                    )
+                });
+        }
+    }
+
+    for article in articles.iter_mut() {
+        let lang = article_language(article, default_language).to_string();
+        let section = section_path(article.prefix());
+        for post in &mut article.posts {
+            let versions = groups.get(translation_group(post));
+            post.translations = app
+                .languages
+                .installed_languages
+                .iter()
+                .map(|code| {
+                    let name = names
+                        .get(code)
+                        .map_or_else(|| code.clone(), |l| l.native_name.clone());
+                    if let Some((url, title)) = versions.and_then(|v| v.get(code)) {
+                        return PostTranslation {
+                            lang: code.clone(),
+                            name,
+                            url: url.clone(),
+                            title: title.clone(),
+                            current: *code == lang,
+                            fallback: false,
+                        };
+                    }
+                    let (path, title) = match sections.get(&(code.clone(), section.clone())) {
+                        Some(title) => (section.as_str(), title.clone()),
+                        None => ("/", app.app_info.app_name.clone()),
+                    };
+                    PostTranslation {
+                        lang: code.clone(),
+                        name,
+                        url: localized_path(default_language, code, path),
+                        title,
+                        current: false,
+                        fallback: true,
+                    }
+                })
+                .collect();
+        }
+    }
+}
+
+/// hreflang tags for a post that exists in more than one language, plus `x-default`
+/// pointing at the default-language version when there is one
+#[must_use]
+pub fn alternates(post: &Post, app: &AppConfig) -> Vec<Alternate> {
+    let domain = app.app_info.app_domain.trim_end_matches('/');
+    let versions: Vec<&PostTranslation> =
+        post.translations.iter().filter(|t| !t.fallback).collect();
+    if versions.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut alternates: Vec<Alternate> = versions
+        .iter()
+        .map(|t| Alternate {
+            hreflang: t.lang.clone(),
+            href: format!("{domain}{}", t.url),
+        })
+        .collect();
+    if let Some(default_version) = versions
+        .iter()
+        .find(|t| t.lang == app.languages.default_language)
+    {
+        alternates.push(Alternate {
+            hreflang: "x-default".to_string(),
+            href: format!("{domain}{}", default_version.url),
+        });
+    }
+    alternates
+}
+
+fn article_language<'a>(article: &'a Article, default_language: &'a str) -> &'a str {
+    if article.lang().is_empty() {
+        default_language
+    } else {
+        article.lang()
+    }
+}
+
+/// Language-independent path of a section page, `/docs/` or `/` for the root section
+fn section_path(prefix: &Path) -> String {
+    let prefix = prefix.to_string_lossy();
+    let prefix = prefix.trim_matches('/');
+    if prefix.is_empty() {
+        "/".to_string()
+    } else {
+        format!("/{prefix}/")
+    }
+}
@@ -1,32 +1,40 @@
 pub mod app;
 mod blog;
 pub mod deploy;
-pub use shared::error as error;
+pub use shared::error;
 pub mod generator;
 mod posts;
 pub mod shared;
-pub use shared::constants as constants;
-pub use shared::language as language;
-pub use shared::template_manager as template_manager;
-pub use shared::tailwind as tailwind;
-pub use shared::validation as validation;
+pub use shared::constants;
+pub use shared::language;
+pub use shared::tailwind;
+pub use shared::template_manager;
+pub use shared::validation;
 
 #[cfg(test)]
 mod tests;
 use eyre::Result;
+use generator::schedule::BuildOptions;
 use generator::Generator;
 use log::error;
+use std::path::Path;
+use validation::{FileValidator, ValidationConfig};
 
 pub fn main() -> Result<()> {
+    build(&BuildOptions::default())
+}
+
+/// Build the site with command line options such as `--future`
+pub fn build(options: &BuildOptions) -> Result<()> {
     let _ = env_logger::try_init();
 
     let name = env!("CARGO_PKG_NAME");
     let version = env!("CARGO_PKG_VERSION");
-    
+
     println!("\x1b[96m┌{:-^48}┐\x1b[0m", "");
     println!("\x1b[96m│\x1b[97m  {} v{}{:>28}│\x1b[0m", name, version, "");
     println!("\x1b[96m└{:-^48}┘\x1b[0m", "");
-    let blog = match Generator::new("build", "contents") {
+    let blog = match Generator::with_options("build", "contents", options) {
         Ok(generator) => generator,
         Err(e) => {
             error!("│  🔥 Failed to initialize blog generator: {e}");
@@ -40,4 +48,28 @@ pub fn main() -> Result<()> {
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Check every content file without building, print all problems and exit
+/// non-zero when any of them is an error
+pub fn check() -> Result<()> {
+    let _ = env_logger::try_init();
+
+    let app = app::read_config();
+    let contents_dir = app
+        .paths
+        .as_ref()
+        .and_then(|p| p.contents_dir.clone())
+        .unwrap_or_else(|| constants::paths::CONTENTS_DIR.to_string());
+    let validator = FileValidator::new(ValidationConfig::from_app(&app));
+
+    println!("│  🔍 Checking content in {contents_dir}...");
+    let report = generator::check::check_contents(&app, Path::new(&contents_dir), &validator);
+    report.print();
+
+    if report.has_errors() {
+        std::process::exit(1);
+    }
+    println!("│  ✅ No errors found");
+    Ok(())
+}
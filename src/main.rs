@@ -1,5 +1,6 @@
 use genwebblog::constants::app;
 use genwebblog::error::{GenWebBlogError, Result};
+use genwebblog::generator::schedule::BuildOptions;
 use genwebblog::shared;
 use genwebblog::shared::utils::is_online;
 use log::{error, info, warn};
@@ -7,6 +8,7 @@ use reqwest::Client;
 use std::env;
 use std::fs;
 use std::net::TcpListener;
+use std::path::Path;
 use std::process;
 
 #[tokio::main]
@@ -61,22 +63,23 @@ async fn main() -> Result<()> {
         }
         "resize" => run_resize(),
         "logo" => run_resize_logo(),
-        "build" => run_build(),
+        "build" => run_build(&BuildOptions::from_args(&args[2..])),
         "build-lang" => {
             if let Some(lang_code) = args.get(2) {
                 run_build_language(lang_code)
             } else {
-                run_build()
+                run_build(&BuildOptions::default())
             }
         }
+        "check" => run_check(),
         "update" => check_for_update().await,
-        "start" => start_server().await,
+        "start" => start_server(&BuildOptions::from_args(args.get(2..).unwrap_or(&[]))).await,
         "page" => {
             if let Some(page_name) = args.get(2) {
                 create_page(page_name)
             } else {
                 return Err(GenWebBlogError::config(
-                    "Please specify a page name, e.g., ./genwebblog page home",
+                    "Please specify a page name, e.g., ./genwebblog page about or ./genwebblog page legal/privacy",
                 ));
             }
         }
@@ -178,29 +181,42 @@ fn handle_language_cmd(args: &[String]) -> Result<()> {
 fn run_quick_start() -> Result<()> {
     println!("🚀 GenWebBlog Quick Start");
     println!("Creating demo content...");
-    run_build()?;
+    run_build(&BuildOptions::default())?;
     println!("✅ Quick start complete! Run './genwebblog start' to view your site.");
     Ok(())
 }
 
-fn run_build() -> Result<()> {
+fn run_build(options: &BuildOptions) -> Result<()> {
     info!("🔨 Building static site...");
-    if let Err(e) = genwebblog::main() {
+    if options.future {
+        info!("⏩ Including posts dated in the future");
+    }
+    if options.drafts {
+        info!(
+            "📝 Draft preview: drafts are rendered with noindex and left out of sitemaps and feeds"
+        );
+    }
+    if let Err(e) = genwebblog::build(options) {
         return Err(GenWebBlogError::config(format!("Build failed: {e}")));
     }
     info!("✅ Build completed successfully");
     Ok(())
 }
 
+fn run_check() -> Result<()> {
+    info!("🔍 Checking content...");
+    genwebblog::check().map_err(|e| GenWebBlogError::config(format!("Check failed: {e}")))
+}
+
 fn run_build_language(lang_code: &str) -> Result<()> {
     info!("🔨 Building site for language: {lang_code}");
-    run_build()
+    run_build(&BuildOptions::default())
 }
 
-async fn start_server() -> Result<()> {
+async fn start_server(options: &BuildOptions) -> Result<()> {
     info!("🌐 Starting development server...");
 
-    run_build()?;
+    run_build(options)?;
 
     let config = genwebblog::app::read_config();
     let configured_port = config.app_info.app_port;
@@ -236,7 +252,7 @@ async fn run_deploy(is_mock: bool) -> Result<()> {
         return Ok(());
     }
 
-    run_build()?;
+    run_build(&BuildOptions::default())?;
 
     let config = genwebblog::app::read_config();
     let client = Client::new();
@@ -271,7 +287,7 @@ async fn run_deploy(is_mock: bool) -> Result<()> {
 fn run_seo() -> Result<()> {
     info!("🔍 Running SEO analysis...");
 
-    run_build()?;
+    run_build(&BuildOptions::default())?;
 
     println!("│  🚀 Starting SEO scanning...");
     shared::seo::scan_html_files_in_directory("build");
@@ -324,8 +340,8 @@ pub fn run_resize_logo() -> Result<()> {
     let args: Vec<String> = env::args().collect();
 
     if let Some(input_path) = args.get(2) {
-    println!("🔹 Input path: {input_path}");
-    println!("│  🔄 Generating favicons from logo... ");
+        println!("🔹 Input path: {input_path}");
+        println!("│  🔄 Generating favicons from logo... ");
 
         match shared::process_image::process_logo(input_path) {
             Ok(_) => {
@@ -345,43 +361,46 @@ pub fn run_resize_logo() -> Result<()> {
     Ok(())
 }
 
+/// Scaffold `contents/{name}.md`; `legal/privacy` becomes `contents/legal/privacy.md`,
+/// served at `/legal/privacy/`
 fn create_page(name: &str) -> Result<()> {
-    let yml_path = format!("contents/{name}.yml");
-    let html_path = format!("source/templates/{name}.html");
+    let name = name.trim_matches('/').trim_end_matches(".md");
+    let md_path = Path::new("contents").join(format!("{name}.md"));
+    if md_path.exists() {
+        return Err(GenWebBlogError::file_system(
+            &md_path,
+            "Page already exists",
+        ));
+    }
+
+    let slug = name.rsplit('/').next().unwrap_or(name).replace('-', " ");
+    let mut chars = slug.chars();
+    let title: String = chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default();
+    let md_content = format!(
+        r#"---
+title: "{title}"
+description: "Enter your page description here"
+layout: page
+---
 
-    let yml_content = r#"title: ""
-description: ""
-image: ""
+This page's content will be added later...
 "#
-    .to_string();
-
-    let html_content = format!(
-        r"
-<h1>{}</h1>
-<p>{}</p>
-",
-        name.to_uppercase(),
-        "This page's content will be added later..."
     );
 
-    fs::create_dir_all("contents").map_err(|e| {
-        GenWebBlogError::file_system("contents", format!("Failed to create directory: {e}"))
-    })?;
-    fs::create_dir_all("source/templates").map_err(|e| {
-        GenWebBlogError::file_system(
-            "source/templates",
-            format!("Failed to create directory: {e}"),
-        )
-    })?;
-
-    fs::write(&yml_path, yml_content).map_err(|e| {
-        GenWebBlogError::file_system(&yml_path, format!("Failed to write YML file: {e}"))
-    })?;
-    fs::write(&html_path, html_content).map_err(|e| {
-        GenWebBlogError::file_system(&html_path, format!("Failed to write HTML file: {e}"))
+    if let Some(parent) = md_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            GenWebBlogError::file_system(parent, format!("Failed to create directory: {e}"))
+        })?;
+    }
+    fs::write(&md_path, md_content).map_err(|e| {
+        GenWebBlogError::file_system(&md_path, format!("Failed to write page file: {e}"))
     })?;
 
-    println!("✅ Successfully created the page: {name}");
+    println!("✅ Successfully created the page: {}", md_path.display());
+    println!("📝 It is rendered with source/pages/page.html (`layout: page`)");
     Ok(())
 }
 
@@ -454,7 +473,7 @@ fn show_help() {
     println!();
     println!("📝 CONTENT MANAGEMENT:");
     println!("  new <title>         Create a new blog post");
-    println!("  page <name>         Create a simple page");
+    println!("  page <name>         Create a markdown page (e.g. legal/privacy)");
     println!();
     println!("🌍 LANGUAGE MANAGEMENT:");
     println!("  lang list           List all available languages");
@@ -465,7 +484,10 @@ fn show_help() {
     println!();
     println!("🔨 BUILD & DEPLOY:");
     println!("  build               Build the static site");
+    println!("  build --future      Build including posts dated in the future");
+    println!("  build --drafts      Preview build including drafts (also: start --drafts)");
     println!("  build-lang <code>   Build for specific language");
+    println!("  check               Validate all content without building");
     println!("  deploy              Deploy to production");
     println!("  deploy test         Test deployment (mock)");
     println!();
@@ -1,9 +1,24 @@
 use crate::{app::AppConfig, shared};
+use chrono::Datelike;
 use eyre::{eyre, WrapErr};
 use log::{error, warn};
 use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
+use crate::generator::git_info::GitCommit;
+use crate::generator::schedule::parse_date;
+use crate::generator::translation_links::PostTranslation;
+use crate::shared::bundle::{self, Bundle};
+use crate::shared::frontmatter;
+use crate::shared::params::{self, Params};
+use crate::shared::permalink::{self, PermalinkParts};
+use crate::shared::summary;
+use crate::shared::toc::TocEntry;
+use crate::shared::url::slugify;
+use crate::shared::utils::{calculate_reading_time, strip_html_tags};
+use crate::shared::word_count::{text_stats, TextStats};
+
 fn default_false() -> bool {
     false
 }
@@ -16,6 +31,7 @@ fn default_locale() -> String {
     "th_TH".to_string()
 }
 
+/// Post front matter, written as YAML, TOML or JSON
 #[derive(Debug, PartialEq, Deserialize)]
 struct YamlHeader {
     #[serde(default = "default_false")]
@@ -24,8 +40,17 @@ struct YamlHeader {
     layout: String,
     #[serde(default)]
     title: String,
+    /// URL slug replacing the part of the filename after the date
+    #[serde(default)]
+    slug: Option<String>,
     #[serde(default)]
     author: String,
+    /// Ids of co-authors from the author registry
+    #[serde(default)]
+    authors: Option<Vec<String>>,
+    /// Old URLs of the post, redirected to its current URL
+    #[serde(default)]
+    aliases: Option<Vec<String>>,
     #[serde(rename = "author_url")]
     #[serde(default)]
     author_url: String,
@@ -46,8 +71,20 @@ struct YamlHeader {
     html_code: Option<String>,
     #[serde(default)]
     date_modified: Option<String>,
+    /// Publish date, RFC 3339 or `YYYY-MM-DD`; wins over the filename date
+    #[serde(default)]
+    date: Option<String>,
     #[serde(default)]
     date_published: Option<String>,
+    /// The post is removed from the site once this date has passed
+    #[serde(default)]
+    expiry_date: Option<String>,
+    /// Name of the multi-part series the post belongs to
+    #[serde(default)]
+    series: Option<String>,
+    /// Position within the series; posts without one follow in date order
+    #[serde(default)]
+    series_part: Option<u32>,
     #[serde(default)]
     category: Option<String>,
     #[serde(default)]
@@ -69,9 +106,21 @@ struct YamlHeader {
     locale_alternate: String,
     #[serde(default)]
     link_video: Option<String>,
+    /// Shared by the language versions of a post; without it, posts with the same URL pair up
+    #[serde(default)]
+    translation_key: Option<String>,
+    /// `false` leaves the post without a table of contents
+    #[serde(default)]
+    toc: Option<bool>,
+    /// Custom fields for templates, laid over the unknown keys below
+    #[serde(default)]
+    params: Option<Params>,
+    /// Frontmatter keys without a dedicated field (custom taxonomies and the like)
+    #[serde(flatten)]
+    extra: BTreeMap<String, serde_yaml::Value>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct Post {
     pub(crate) filename: String,
     pub(crate) layout: String,
@@ -89,6 +138,10 @@ pub struct Post {
     pub(crate) author: String,
     pub(crate) author_url: String,
     pub(crate) author_email: String,
+    /// Registry ids of the post's authors, resolved from `author`/`authors`
+    pub(crate) authors: Vec<String>,
+    /// Old site paths that redirect to this post
+    pub(crate) aliases: Vec<String>,
     pub(crate) image: String,
     pub(crate) image_secure_url: Option<String>,
     pub(crate) image_type: Option<String>,
@@ -106,59 +159,75 @@ pub struct Post {
     pub(crate) html_code: Option<String>,
     pub(crate) date_modified: Option<String>,
     pub(crate) date_published: Option<String>,
+    pub(crate) expiry_date: Option<String>,
+    pub(crate) series: Option<String>,
+    pub(crate) series_part: Option<u32>,
     pub(crate) category: Option<String>,
     pub(crate) locale: Option<String>,
     pub(crate) locale_alternate: Option<String>,
     pub(crate) link_video: Option<String>,
+    pub(crate) translation_key: Option<String>,
+    /// Every installed language's version of the post, or its section page when untranslated
+    pub(crate) translations: Vec<PostTranslation>,
+    pub(crate) taxonomies: BTreeMap<String, Vec<String>>,
+    pub(crate) word_count: usize,
+    /// Characters other than whitespace
+    pub(crate) char_count: usize,
+    /// Minutes, from `word_count` and the section language's `[reading] words_per_minute`
+    pub(crate) reading_time: u32,
+    /// Headings between `[toc] min_level` and `max_level`, nested
+    pub(crate) toc: Vec<TocEntry>,
+    /// `toc` rendered as nested `<ol>` links, empty when the post has no table of contents
+    pub(crate) toc_html: String,
+    /// Plain text of the part before `<!--more-->`, or the opening of the post
+    pub(crate) summary: String,
+    /// The part before `<!--more-->` rendered, or `summary` as a paragraph
+    pub(crate) summary_html: String,
+    /// Set for a post written as `YYYY-MM-DD-slug/index.md` with its files beside it
+    #[serde(skip)]
+    pub(crate) bundle: Option<Bundle>,
+    /// The Markdown file the post was read from
+    #[serde(skip)]
+    pub(crate) source: PathBuf,
+    /// Last commit touching the file, with `[git] last_modified`
+    pub(crate) git: Option<GitCommit>,
+    /// `[git] edit_url` for the file
+    pub(crate) edit_url: Option<String>,
+    /// Custom front matter fields: unknown keys and the `params:` map
+    pub(crate) params: Params,
 }
 
 impl Post {
     #[allow(clippy::too_many_lines)]
-    pub(crate) fn open(path: &Path, app: &AppConfig, prefix: &Path) -> eyre::Result<Self> {
-        let filename = path
-            .file_name()
-            .and_then(|name| name.to_str())
-            .ok_or_else(|| eyre!("Invalid filename in path: {:?}", path))?;
-
-        let mut split = filename.splitn(4, '-');
-
-        let year = split
-            .next()
-            .ok_or_else(|| eyre!("Missing year in filename: {}", filename))?
-            .parse::<i32>()
-            .wrap_err_with(|| format!("Invalid year in filename: {filename}"))?;
-        let month = split
-            .next()
-            .ok_or_else(|| eyre!("Missing month in filename: {}", filename))?
-            .parse::<u32>()
-            .wrap_err_with(|| format!("Invalid month in filename: {filename}"))?;
-        let day = split
-            .next()
-            .ok_or_else(|| eyre!("Missing day in filename: {}", filename))?
-            .parse::<u32>()
-            .wrap_err_with(|| format!("Invalid day in filename: {filename}"))?;
-        let filename = split
-            .next()
-            .ok_or_else(|| eyre!("Missing title in filename: {}", filename))?
-            .to_string();
+    pub(crate) fn open(
+        path: &Path,
+        app: &AppConfig,
+        prefix: &Path,
+        permalink: &str,
+        root: &str,
+        markdown: &comrak::Options,
+        words_per_minute: u32,
+    ) -> eyre::Result<Self> {
+        let bundle_dir = bundle::bundle_dir(path);
+        let filename = source_filename(path)?;
+        let filename = filename.as_str();
 
-        let contents = crate::shared::fs::read_file_to_string(path)?;
-        if contents.len() < 5 {
-            return Err(eyre!(
-                "{path:?} is empty, or too short to have valid front matter"
-            ));
-        }
+        // `YYYY-MM-DD-slug.md`, or an undated `slug.md` whose date is in the front matter
+        let (filename_date, filename) = match split_date_prefix(filename) {
+            Some((date, rest)) => (Some(date), rest.to_string()),
+            None => (None, filename.to_string()),
+        };
 
-        let end_of_yaml = contents[4..]
-            .find("---")
-            .ok_or_else(|| eyre!("Missing closing '---' in front matter of file: {:?}", path))?
-            + 4;
-        let yaml = &contents[..end_of_yaml];
+        let contents = crate::shared::fs::read_file_to_string(path)?;
+        let (header, body) = frontmatter::parse::<YamlHeader>(path, &contents)?;
         let YamlHeader {
             author_url,
             author_email,
             author,
+            authors,
+            aliases,
             title,
+            slug,
             draft,
             layout,
             image,
@@ -174,51 +243,115 @@ impl Post {
             link_name,
             html_code,
             date_modified,
+            date,
             date_published,
+            expiry_date,
+            series,
+            series_part,
             category,
             locale,
             locale_alternate,
             link_video,
-        } = serde_yaml::from_str(yaml)?;
-
-        let options = comrak::Options {
-            render: comrak::RenderOptions::builder().unsafe_(true).build(),
-            extension: comrak::ExtensionOptions::builder()
-                .header_ids(String::new())
-                .strikethrough(true)
-                .footnotes(true)
-                .table(true)
-                .build(),
-            ..comrak::Options::default()
-        };
+            translation_key,
+            toc,
+            params,
+            extra,
+        } = header;
 
-        let contents = shared::markdown_to_html::comrak_custom::markdown_to_html(
-            &contents[end_of_yaml + 4..],
-            &options,
+        let ((year, month, day), mismatch) = publish_date(
+            path,
+            date.as_deref().or(date_published.as_deref()),
+            filename_date,
+        )?;
+        if let Some(mismatch) = mismatch {
+            warn!("│  ⚠️ {}: {mismatch}", path.display());
+        }
+        let frontmatter_date = date
+            .as_deref()
+            .or(date_published.as_deref())
+            .and_then(parse_date);
+
+        let params = params::collect(&extra, params);
+        params::validate(&params, app)
+            .map_err(|e| eyre!("Invalid params in {}: {e}", path.display()))?;
+
+        let taxonomies = crate::generator::taxonomy::terms_from_frontmatter(
+            app,
+            tags.as_ref(),
+            category.as_ref(),
+            &extra,
         );
 
-        let mut url = PathBuf::from(&*filename);
-        url.set_extension("html");
+        let (contents, headings) =
+            shared::markdown_to_html::comrak_custom::markdown_to_html_with_headings(body, markdown);
+        let (summary, summary_html) = match summary::split_at_separator(body) {
+            Some((before, _)) => {
+                let html =
+                    shared::markdown_to_html::comrak_custom::markdown_to_html(before, markdown);
+                (summary::plain_text(&html), html)
+            }
+            None => summary::auto_summary(&contents, app.summary_length()),
+        };
+        let toc = match shared::toc::levels(app) {
+            Some((min_level, max_level)) if toc != Some(false) => {
+                shared::toc::build(&headings, min_level, max_level)
+            }
+            _ => Vec::new(),
+        };
+        let toc_html = shared::toc::render(&toc);
 
-        // {:04}/{:02}/{:02}/
-        let url = url
-            .to_str()
-            .ok_or_else(|| eyre!("Invalid URL path: {:?}", url))?
-            .to_string();
+        let TextStats {
+            word_count,
+            char_count,
+        } = text_stats(&strip_html_tags(&contents));
 
-        let published = build_time(year, month, day, 0);
+        let published = frontmatter_date
+            .map_or_else(|| build_time(year, month, day, 0), |date| date.to_rfc3339());
         let updated = published.clone();
         let keywords: Vec<String> = keywords.unwrap_or_else(Vec::new);
         let formatted_keywords = keywords.join(", ");
 
-        let full_url = if prefix.as_os_str().is_empty() {
-            format!("/{url}")
-        } else {
-            let valid_prefix_clone = prefix;
-            format!("/{}/{}", valid_prefix_clone.display(), url)
-                .to_string()
-                .to_string()
+        let slug = slug
+            .as_deref()
+            .map(slugify)
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| {
+                Path::new(&filename)
+                    .file_stem()
+                    .map_or_else(|| filename.clone(), |s| s.to_string_lossy().to_string())
+            });
+        let use_directory = app
+            .paths
+            .as_ref()
+            .and_then(|p| p.use_directory_structure)
+            .unwrap_or(true);
+        let section = prefix.to_string_lossy().replace('\\', "/");
+        let full_url = permalink::expand(
+            permalink,
+            &PermalinkParts {
+                section: &section,
+                year,
+                month,
+                day,
+                slug: &slug,
+                category: category.as_deref(),
+            },
+            use_directory,
+        )
+        .wrap_err_with(|| format!("Invalid permalink for {}", path.display()))?;
+
+        let bundle = bundle_dir.map(|dir| Bundle::new(dir, root, &full_url));
+        let (contents, summary_html) = match &bundle {
+            Some(bundle) => (
+                bundle.rewrite_links(&contents),
+                bundle.rewrite_links(&summary_html),
+            ),
+            None => (contents, summary_html),
         };
+        // `image: cover.jpg` in a bundle names the file beside `index.md`
+        let bundle_image = bundle
+            .as_ref()
+            .and_then(|b| b.resolve(&image).map(|url| (b.dir.join(&image), url)));
 
         let is_image = !image.is_empty();
         let default_image = if image.is_empty() {
@@ -226,9 +359,16 @@ impl Post {
         } else {
             "/favicon/favicon.svg".to_string()
         };
-        let image = shared::utils::get_image(Some(&image), &default_image);
+        let image = match &bundle_image {
+            Some((_, url)) => url.clone(),
+            None => shared::utils::get_image(Some(&image), &default_image),
+        };
         let image_resize = if is_image && !image.ends_with(".svg") {
-            shared::process_image::process_image(&image).unwrap_or_else(|e| {
+            match &bundle_image {
+                Some((source, url)) => shared::process_image::process_image_file(source, url),
+                None => shared::process_image::process_image(&image),
+            }
+            .unwrap_or_else(|e| {
                 warn!("│  ⚠️ Error processing image: {e}");
                 String::new()
             })
@@ -272,6 +412,8 @@ impl Post {
             author,
             author_url,
             author_email,
+            authors: authors.unwrap_or_default(),
+            aliases: aliases.unwrap_or_default(),
             year,
             show_year: false,
             month,
@@ -300,18 +442,125 @@ impl Post {
             root: "../".to_string(),
             date_modified,
             date_published,
+            expiry_date,
+            series,
+            series_part,
             category,
             locale: Some(locale),
             locale_alternate: Some(locale_alternate),
             link_video,
+            translation_key,
+            translations: Vec::new(),
+            taxonomies,
+            word_count,
+            char_count,
+            reading_time: calculate_reading_time(word_count, words_per_minute),
+            toc,
+            toc_html,
+            summary,
+            summary_html,
+            bundle,
+            source: path.to_path_buf(),
+            git: None,
+            edit_url: None,
+            params,
         })
     }
 
+    /// Frontmatter `description`, or the summary for posts without one
+    #[must_use]
+    pub fn meta_description(&self) -> &str {
+        if self.description.is_empty() {
+            &self.summary
+        } else {
+            &self.description
+        }
+    }
+
     pub fn set_updated(&mut self, seconds: u32) {
         self.updated = build_time(self.year, self.month, self.day, seconds);
     }
 }
 
+/// Check what [`Post::open`] would reject without rendering the post.
+/// Returns warnings, such as a front matter date that differs from the filename.
+pub(crate) fn check(path: &Path, app: &AppConfig) -> eyre::Result<Vec<String>> {
+    let filename = source_filename(path)?;
+    let filename_date = split_date_prefix(&filename).map(|(date, _)| date);
+    let contents = crate::shared::fs::read_file_to_string(path)?;
+    let (header, _) = frontmatter::parse::<YamlHeader>(path, &contents)?;
+    let (_, mismatch) = publish_date(
+        path,
+        header.date.as_deref().or(header.date_published.as_deref()),
+        filename_date,
+    )?;
+    params::validate(&params::collect(&header.extra, header.params), app)
+        .map_err(|e| eyre!("Invalid params in {}: {e}", path.display()))?;
+    Ok(mismatch.into_iter().collect())
+}
+
+/// File name a post is named by; a bundle's `index.md` takes its directory's name
+fn source_filename(path: &Path) -> eyre::Result<String> {
+    match bundle::bundle_dir(path) {
+        Some(dir) => dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| format!("{name}.md")),
+        None => path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(str::to_string),
+    }
+    .ok_or_else(|| eyre!("Invalid filename in path: {:?}", path))
+}
+
+/// Publish day from the front matter `date`, else the filename, and a note when they differ
+fn publish_date(
+    path: &Path,
+    frontmatter_date: Option<&str>,
+    filename_date: Option<(i32, u32, u32)>,
+) -> eyre::Result<((i32, u32, u32), Option<String>)> {
+    let frontmatter_date = frontmatter_date
+        .map(|value| {
+            parse_date(value).ok_or_else(|| {
+                eyre!(
+                    "Invalid date \"{value}\" in {}: use RFC 3339 such as \
+                     2025-07-03T09:00:00+07:00, or YYYY-MM-DD",
+                    path.display()
+                )
+            })
+        })
+        .transpose()?;
+    match (frontmatter_date, filename_date) {
+        (Some(date), filename_date) => {
+            let date_ymd = (date.year(), date.month(), date.day());
+            let mismatch = filename_date.filter(|f| *f != date_ymd).map(|(y, m, d)| {
+                format!(
+                    "front matter date {} differs from the filename date \
+                     {y:04}-{m:02}-{d:02}, using the front matter date",
+                    date.date_naive()
+                )
+            });
+            Ok((date_ymd, mismatch))
+        }
+        (None, Some(filename_date)) => Ok((filename_date, None)),
+        (None, None) => Err(eyre!(
+            "{} has no date: name it YYYY-MM-DD-slug.md or set `date` in the front matter",
+            path.display()
+        )),
+    }
+}
+
+/// `(year, month, day)` and the rest of a `YYYY-MM-DD-rest` filename
+fn split_date_prefix(filename: &str) -> Option<((i32, u32, u32), &str)> {
+    let mut split = filename.splitn(4, '-');
+    let year = split.next()?.parse().ok()?;
+    let month = split.next()?.parse().ok()?;
+    let day = split.next()?.parse().ok()?;
+    let rest = split.next().filter(|rest| !rest.is_empty())?;
+    Some(((year, month, day), rest))
+}
+
 fn build_time(year: i32, month: u32, day: u32, seconds: u32) -> String {
     let date = match chrono::NaiveDate::from_ymd_opt(year, month, day) {
         Some(d) => d,
@@ -0,0 +1,105 @@
+//! Page bundles: a post as `YYYY-MM-DD-slug/index.md` with its images and downloads beside it
+
+use std::path::{Path, PathBuf};
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+
+use crate::constants::files::BUNDLE_INDEX;
+
+static LINK_ATTRIBUTE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\b(src|href)="([^"]*)""#).expect("valid link attribute regex"));
+
+/// Where a bundle's files are read from and the URL they are served at
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bundle {
+    pub dir: PathBuf,
+    /// Directory URL with a trailing slash, including the language root
+    pub url: String,
+}
+
+impl Bundle {
+    /// `dir` served at the directory of `page_url`: `/seo/guide/` for both `/seo/guide/`
+    /// and `/seo/guide.html`, prefixed with `root` (`""` or `/en`)
+    #[must_use]
+    pub fn new(dir: &Path, root: &str, page_url: &str) -> Self {
+        let base = page_url
+            .strip_suffix(".html")
+            .unwrap_or(page_url)
+            .trim_end_matches('/');
+        Self {
+            dir: dir.to_path_buf(),
+            url: format!("{root}{base}/"),
+        }
+    }
+
+    /// URL of `relative` when it names a file in the bundle
+    #[must_use]
+    pub fn resolve(&self, relative: &str) -> Option<String> {
+        // Absolute paths, fragments and anything with a scheme (https:, mailto:, data:)
+        let has_scheme = relative
+            .split('/')
+            .next()
+            .is_some_and(|first| first.contains(':'));
+        if relative.is_empty() || relative.starts_with(['/', '#', '?']) || has_scheme {
+            return None;
+        }
+        let path = relative
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches("./");
+        let in_bundle = !path.split('/').any(|part| part == "..")
+            && path != BUNDLE_INDEX
+            && self.dir.join(path).is_file();
+        in_bundle.then(|| format!("{}{}", self.url, relative.trim_start_matches("./")))
+    }
+
+    /// Point relative `src`/`href` attributes at bundle files to their published URLs
+    #[must_use]
+    pub fn rewrite_links(&self, html: &str) -> String {
+        LINK_ATTRIBUTE
+            .replace_all(html, |caps: &Captures| match self.resolve(&caps[2]) {
+                Some(url) => format!("{}=\"{url}\"", &caps[1]),
+                None => caps[0].to_string(),
+            })
+            .into_owned()
+    }
+
+    /// Files of the bundle other than `index.md`, relative to its directory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bundle directory cannot be read
+    pub fn assets(&self) -> eyre::Result<Vec<PathBuf>> {
+        let mut assets = Vec::new();
+        for entry in walkdir::WalkDir::new(&self.dir).min_depth(1) {
+            let entry = entry?;
+            if entry.file_type().is_file() {
+                let relative = entry.path().strip_prefix(&self.dir)?.to_path_buf();
+                if relative != Path::new(BUNDLE_INDEX) {
+                    assets.push(relative);
+                }
+            }
+        }
+        assets.sort();
+        Ok(assets)
+    }
+}
+
+/// The `index.md` of a bundle directory, if `dir` is one
+#[must_use]
+pub fn index_file(dir: &Path) -> Option<PathBuf> {
+    let index = dir.join(BUNDLE_INDEX);
+    index.is_file().then_some(index)
+}
+
+/// Directory of a bundle post, given its `index.md`
+#[must_use]
+pub fn bundle_dir(post_path: &Path) -> Option<&Path> {
+    if post_path.file_name()? == BUNDLE_INDEX {
+        post_path.parent()
+    } else {
+        None
+    }
+}
@@ -1,5 +1,5 @@
+use log::{error, info};
 use std::process::Command;
-use log::{info, error};
 
 fn install_choco_if_missing() -> Result<(), String> {
     let choco_check = Command::new("where")
@@ -29,7 +29,6 @@ fn install_choco_if_missing() -> Result<(), String> {
 }
 
 pub fn install_required_tool(command: &str) -> Result<(), String> {
-
     let os = if cfg!(target_os = "linux") {
         "linux"
     } else if cfg!(target_os = "macos") {
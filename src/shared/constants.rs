@@ -1,4 +1,3 @@
-
 use std::collections::HashMap;
 
 /// Application metadata constants
@@ -30,6 +29,9 @@ pub mod paths {
     /// Public assets directory
     pub const PUBLIC_DIR: &str = "public";
 
+    /// Data files exposed to templates as `site.data`
+    pub const DATA_DIR: &str = "data";
+
     /// Source templates and layouts directory
     pub const SOURCE_DIR: &str = "source";
 
@@ -56,6 +58,10 @@ pub mod paths {
 
     /// Images directory
     pub const IMAGES_DIR: &str = "public/images";
+
+    /// Post URL pattern when neither `[paths] permalink` nor a section's `permalink` is set,
+    /// the `.html` URLs posts always had
+    pub const DEFAULT_PERMALINK: &str = "/{section}/{slug}.html";
 }
 
 /// File extension constants
@@ -90,6 +96,9 @@ pub mod files {
     /// Article manifest file name
     pub const MANIFEST: &str = "index.yml";
 
+    /// Post file of a page bundle directory
+    pub const BUNDLE_INDEX: &str = "index.md";
+
     /// Site configuration file
     pub const SITE_CONFIG: &str = "site.yml";
 
@@ -107,6 +116,12 @@ pub mod files {
 
     /// Releases JSON file name
     pub const RELEASES_JSON: &str = "releases.json";
+
+    /// Cloudflare Pages / Netlify redirect rules
+    pub const REDIRECTS: &str = "_redirects";
+
+    /// Author registry, next to `app.toml`
+    pub const AUTHORS: &str = "authors.yml";
 }
 
 /// Default favicon paths
@@ -144,6 +159,15 @@ pub mod seo {
     /// Default robots content
     pub const DEFAULT_ROBOTS_CONTENT: &str = "follow, index";
 
+    /// Robots content of drafts rendered by preview builds
+    pub const DRAFT_ROBOTS_CONTENT: &str = "noindex, nofollow";
+
+    /// Titles longer than this may be truncated in search results
+    pub const TITLE_MAX_LENGTH: usize = 60;
+
+    /// Descriptions longer than this may be truncated in search results
+    pub const DESCRIPTION_MAX_LENGTH: usize = 160;
+
     /// Default sitemap change frequency
     pub const DEFAULT_SITEMAP_CHANGEFREQ: &str = "weekly";
 
@@ -166,6 +190,43 @@ pub mod seo {
     pub const PAGE_TYPE_CONTACT: &str = "ContactPage";
 }
 
+/// Listing pagination constants
+pub mod pagination {
+    /// Posts per page when neither `[pagination] page_size` nor a section's `paginate` is set
+    pub const DEFAULT_PAGE_SIZE: usize = 10;
+}
+
+/// Related posts scoring constants
+pub mod related {
+    /// Related posts per post when `[related] limit` is not set
+    pub const DEFAULT_LIMIT: usize = 3;
+    pub const DEFAULT_TAG_WEIGHT: f64 = 3.0;
+    pub const DEFAULT_CATEGORY_WEIGHT: f64 = 2.0;
+    pub const DEFAULT_KEYWORD_WEIGHT: f64 = 1.0;
+    /// Cosine similarity a post needs to be suggested on text alone
+    pub const MIN_SIMILARITY: f64 = 0.2;
+    /// Characters of title, description and body compared for similarity
+    pub const SIMILARITY_TEXT_CHARS: usize = 2000;
+}
+
+/// Table of contents constants
+pub mod toc {
+    /// Shallowest heading listed when `[toc] min_level` is not set
+    pub const DEFAULT_MIN_LEVEL: u8 = 2;
+    /// Deepest heading listed when `[toc] max_level` is not set
+    pub const DEFAULT_MAX_LEVEL: u8 = 3;
+    /// Fewer listed headings than this and the post gets no table of contents
+    pub const MIN_HEADINGS: usize = 2;
+}
+
+/// Git history constants
+pub mod git {
+    /// Last-commit cache, kept inside the repository's `.git` directory
+    pub const CACHE_FILE: &str = "genwebblog-last-commits.json";
+    /// Placeholder in `[git] edit_url` replaced by the file's repository path
+    pub const EDIT_URL_PATH: &str = "{path}";
+}
+
 /// Supported image formats
 pub mod images {
     /// Modern image formats (preferred)
@@ -178,9 +239,7 @@ pub mod images {
     pub const VECTOR_FORMATS: &[&str] = &["svg"];
 
     /// All supported image formats
-    pub const ALL_FORMATS: &[&str] = &[
-        "webp", "avif", "jpg", "jpeg", "png", "gif", "svg", "bmp",
-    ];
+    pub const ALL_FORMATS: &[&str] = &["webp", "avif", "jpg", "jpeg", "png", "gif", "svg", "bmp"];
 }
 
 /// Language and locale constants
@@ -203,6 +262,15 @@ pub mod validation {
     /// Maximum filename length (without extension)
     pub const MAX_FILENAME_LENGTH: usize = 80;
 
+    /// Larger images referenced by a post get a warning
+    pub const MAX_IMAGE_SIZE_KB: u64 = 1024;
+
+    /// Wider images referenced by a post get a warning
+    pub const MAX_IMAGE_WIDTH: u32 = 2560;
+
+    /// Taller images referenced by a post get a warning
+    pub const MAX_IMAGE_HEIGHT: u32 = 2560;
+
     /// Date format regex pattern for filename validation
     pub const DATE_PATTERN: &str = r"^\d{4}-\d{2}-\d{2}$";
 
@@ -272,9 +340,15 @@ pub mod content {
     /// Default excerpt length in characters
     pub const DEFAULT_EXCERPT_LENGTH: usize = 160;
 
+    /// Line in a post that ends its summary
+    pub const SUMMARY_SEPARATOR: &str = "<!--more-->";
+
     /// Default reading time words per minute
     pub const READING_TIME_WPM: u32 = 200;
 
+    /// Thai text the dictionary does not cover is counted as one word per this many characters
+    pub const THAI_CHARS_PER_UNKNOWN_WORD: usize = 5;
+
     /// Keywords separator
     pub const KEYWORDS_SEPARATOR: &str = ", ";
 }
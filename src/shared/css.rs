@@ -19,7 +19,11 @@ pub fn compile_sass(filename: &str) -> Result<()> {
                 GenWebBlogError::file_system(parent, format!("Failed to create directory: {e}"))
             })?;
         }
-        crate::shared::fs::write_file(&css_file, b"/* CSS file generated - SCSS source not found */\n").map_err(|e| {
+        crate::shared::fs::write_file(
+            &css_file,
+            b"/* CSS file generated - SCSS source not found */\n",
+        )
+        .map_err(|e| {
             GenWebBlogError::css_compilation(&css_file, format!("Failed to write CSS file: {e}"))
         })?;
         return Ok(());
@@ -36,9 +40,17 @@ pub fn compile_sass(filename: &str) -> Result<()> {
     match grass::from_path(&scss_file, &grass::Options::default()) {
         Ok(css_content) => {
             crate::shared::fs::write_file(&css_file, css_content.as_bytes()).map_err(|e| {
-                GenWebBlogError::css_compilation(&css_file, format!("Failed to write CSS file: {e}"))
+                GenWebBlogError::css_compilation(
+                    &css_file,
+                    format!("Failed to write CSS file: {e}"),
+                )
             })?;
-            println!("│  ✅ SASS compiled with grass: {} → {} ({} bytes)", scss_file, css_file, css_content.len());
+            println!(
+                "│  ✅ SASS compiled with grass: {} → {} ({} bytes)",
+                scss_file,
+                css_file,
+                css_content.len()
+            );
         }
         Err(e) => {
             println!("│  ⚠️ SASS compilation failed ({e}), using fallback");
@@ -50,18 +62,34 @@ pub fn compile_sass(filename: &str) -> Result<()> {
                         .collect::<Vec<&str>>()
                         .join("\n");
 
-                    crate::shared::fs::write_file(&css_file, css_content.as_bytes()).map_err(|e| {
-                        GenWebBlogError::css_compilation(&css_file, format!("Failed to write CSS file: {e}"))
-                    })?;
-                    println!("│  ⚡ Fallback CSS generated: {} ({} bytes)", css_file, css_content.len());
+                    crate::shared::fs::write_file(&css_file, css_content.as_bytes()).map_err(
+                        |e| {
+                            GenWebBlogError::css_compilation(
+                                &css_file,
+                                format!("Failed to write CSS file: {e}"),
+                            )
+                        },
+                    )?;
+                    println!(
+                        "│  ⚡ Fallback CSS generated: {} ({} bytes)",
+                        css_file,
+                        css_content.len()
+                    );
                 }
                 Err(_) => {
                     let mut file = File::create(&css_file).map_err(|e| {
-                        GenWebBlogError::css_compilation(&css_file, format!("Failed to create CSS file: {e}"))
-                    })?;
-                    file.write_all(b"/* CSS file generated - SCSS source read error */\n").map_err(|e| {
-                        GenWebBlogError::css_compilation(&css_file, format!("Failed to write CSS file: {e}"))
+                        GenWebBlogError::css_compilation(
+                            &css_file,
+                            format!("Failed to create CSS file: {e}"),
+                        )
                     })?;
+                    file.write_all(b"/* CSS file generated - SCSS source read error */\n")
+                        .map_err(|e| {
+                            GenWebBlogError::css_compilation(
+                                &css_file,
+                                format!("Failed to write CSS file: {e}"),
+                            )
+                        })?;
                     println!("│  ❌ Error reading SCSS, empty CSS created: {css_file}");
                 }
             }
@@ -113,6 +141,10 @@ pub fn concat_vendor_css(files: Vec<&str>) -> Result<()> {
         GenWebBlogError::file_system(&vendor_css_path, format!("Failed to write vendor CSS: {e}"))
     })?;
 
-    println!("│  ✅ Vendor CSS created: {} ({} bytes)", vendor_css_path, minified_css.len());
+    println!(
+        "│  ✅ Vendor CSS created: {} ({} bytes)",
+        vendor_css_path,
+        minified_css.len()
+    );
     Ok(())
 }
@@ -20,6 +20,15 @@ pub enum GenWebBlogError {
     #[error("Markdown processing error in {file}: {message}")]
     Markdown { file: PathBuf, message: String },
 
+    /// Front matter that cannot be split or parsed, located in the original file
+    #[error("Front matter error at {}:{line}:{column}: {message}", file.display())]
+    Frontmatter {
+        file: PathBuf,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+
     /// Language management errors
     #[error("Language error: {message}")]
     Language { message: String },
@@ -132,6 +141,21 @@ impl GenWebBlogError {
         }
     }
 
+    /// Create a front matter error at a 1-based line and column of `file`
+    pub fn frontmatter<P: Into<PathBuf>, S: Into<String>>(
+        file: P,
+        line: usize,
+        column: usize,
+        message: S,
+    ) -> Self {
+        Self::Frontmatter {
+            file: file.into(),
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
     /// Create a language error
     pub fn language<S: Into<String>>(message: S) -> Self {
         Self::Language {
@@ -223,6 +247,7 @@ impl GenWebBlogError {
             GenWebBlogError::Validation { .. } => "validation",
             GenWebBlogError::Template { .. } => "template",
             GenWebBlogError::Markdown { .. } => "markdown",
+            GenWebBlogError::Frontmatter { .. } => "frontmatter",
             GenWebBlogError::Language { .. } => "language",
             GenWebBlogError::Deploy { .. } => "deploy",
             GenWebBlogError::GitHub { .. } => "github",
@@ -263,6 +288,17 @@ impl GenWebBlogError {
                     file.display()
                 )
             }
+            GenWebBlogError::Frontmatter {
+                file,
+                line,
+                column,
+                message,
+            } => {
+                format!(
+                    "📝 Front matter error at {}:{line}:{column}: {message}",
+                    file.display()
+                )
+            }
             GenWebBlogError::Language { message } => {
                 format!("🌍 Language issue: {message}")
             }
@@ -0,0 +1,231 @@
+//! Front matter at the top of a markdown file: YAML between `---` lines, TOML between
+//! `+++` lines, or a JSON object. Errors point at the line in the original file.
+
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::de::DeserializeOwned;
+
+use crate::shared::error::{GenWebBlogError, Result};
+
+static PARSER_POSITION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r" at line \d+ column \d+").expect("valid parser position regex"));
+
+/// Syntax of a front matter block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Yaml,
+    Toml,
+    Json,
+}
+
+/// A front matter block split from the rest of the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frontmatter<'a> {
+    pub format: Format,
+    /// The block without its delimiters (JSON keeps its braces)
+    pub raw: &'a str,
+    /// 1-based line of the file where `raw` starts
+    pub first_line: usize,
+    /// Everything after the block
+    pub body: &'a str,
+}
+
+impl Frontmatter<'_> {
+    /// Deserialize the block, reporting errors at their line in `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GenWebBlogError::Frontmatter` for invalid syntax or mismatched fields
+    pub fn deserialize<T: DeserializeOwned>(&self, path: &Path) -> Result<T> {
+        match self.format {
+            Format::Yaml => parse_yaml(path, self.raw, self.first_line),
+            Format::Toml => parse_toml(path, self.raw, self.first_line),
+            Format::Json => serde_json::from_str(self.raw).map_err(|e| {
+                located(
+                    path,
+                    self.first_line + e.line().saturating_sub(1),
+                    e.column(),
+                    &e.to_string(),
+                )
+            }),
+        }
+    }
+
+    /// Line of `path` where the top-level `key` is set, if it can be found
+    #[must_use]
+    pub fn line_of(&self, key: &str) -> Option<usize> {
+        self.raw
+            .lines()
+            .position(|line| {
+                let rest = match self.format {
+                    Format::Yaml => line.strip_prefix(key).map(|r| r.starts_with(':')),
+                    Format::Toml => line
+                        .strip_prefix(key)
+                        .map(|r| r.trim_start().starts_with('=')),
+                    Format::Json => line
+                        .trim_start()
+                        .strip_prefix(&format!("\"{key}\""))
+                        .map(|r| r.trim_start().starts_with(':')),
+                };
+                rest.unwrap_or(false)
+            })
+            .map(|index| self.first_line + index)
+    }
+}
+
+/// Split the front matter from `content`, or `None` when the file has none.
+///
+/// Delimiters must be on lines of their own, so `---` inside a YAML string is not a delimiter.
+///
+/// # Errors
+///
+/// Returns an error when a block is opened but never closed
+pub fn split<'a>(path: &Path, content: &'a str) -> Result<Option<Frontmatter<'a>>> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let first = content.lines().next().unwrap_or_default().trim_end();
+    let (format, delimiter) = match first {
+        "---" => (Format::Yaml, "---"),
+        "+++" => (Format::Toml, "+++"),
+        _ if first.starts_with('{') => return split_json(path, content).map(Some),
+        _ => return Ok(None),
+    };
+
+    let start = content.find('\n').map_or(content.len(), |i| i + 1);
+    let mut offset = start;
+    for line in content[start..].split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        // YAML may also end a document with `...`
+        if trimmed == delimiter || (format == Format::Yaml && trimmed == "...") {
+            return Ok(Some(Frontmatter {
+                format,
+                raw: &content[start..offset],
+                first_line: 2,
+                body: &content[offset + line.len()..],
+            }));
+        }
+        offset += line.len();
+    }
+    Err(GenWebBlogError::frontmatter(
+        path,
+        1,
+        1,
+        format!("front matter opened with `{delimiter}` is never closed"),
+    ))
+}
+
+/// Split and deserialize the front matter of `content`, returning it with the body
+///
+/// # Errors
+///
+/// Returns an error when the file has no front matter or it cannot be parsed
+pub fn parse<'a, T: DeserializeOwned>(path: &Path, content: &'a str) -> Result<(T, &'a str)> {
+    let frontmatter = split(path, content)?.ok_or_else(|| {
+        GenWebBlogError::frontmatter(
+            path,
+            1,
+            1,
+            "missing front matter: start the file with `---` (YAML), `+++` (TOML) or `{` (JSON)",
+        )
+    })?;
+    Ok((frontmatter.deserialize(path)?, frontmatter.body))
+}
+
+/// Parse YAML that starts at `first_line` of `path`, such as a whole `.yml` file
+///
+/// # Errors
+///
+/// Returns a `GenWebBlogError::Frontmatter` located at the offending line
+pub fn parse_yaml<T: DeserializeOwned>(path: &Path, yaml: &str, first_line: usize) -> Result<T> {
+    serde_yaml::from_str(yaml).map_err(|e| match e.location() {
+        Some(location) => located(
+            path,
+            first_line + location.line().saturating_sub(1),
+            location.column(),
+            &e.to_string(),
+        ),
+        None => located(path, first_line, 1, &e.to_string()),
+    })
+}
+
+fn parse_toml<T: DeserializeOwned>(path: &Path, toml_text: &str, first_line: usize) -> Result<T> {
+    let mut value: toml::Value = toml_text.parse().map_err(|e: toml::de::Error| {
+        let (line, column) = e
+            .span()
+            .map_or((1, 1), |span| line_and_column(toml_text, span.start));
+        located(path, first_line + line - 1, column, e.message())
+    })?;
+    // Native TOML dates become RFC 3339 strings, as they are written in YAML
+    stringify_datetimes(&mut value);
+    T::deserialize(value).map_err(|e| located(path, first_line, 1, e.message()))
+}
+
+fn split_json<'a>(path: &Path, content: &'a str) -> Result<Frontmatter<'a>> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in content.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let rest = &content[i + 1..];
+                    let body = rest
+                        .strip_prefix("\r\n")
+                        .or_else(|| rest.strip_prefix('\n'))
+                        .unwrap_or(rest);
+                    return Ok(Frontmatter {
+                        format: Format::Json,
+                        raw: &content[..=i],
+                        first_line: 1,
+                        body,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(GenWebBlogError::frontmatter(
+        path,
+        1,
+        1,
+        "JSON front matter is missing its closing `}`",
+    ))
+}
+
+fn stringify_datetimes(value: &mut toml::Value) {
+    match value {
+        toml::Value::Datetime(datetime) => *value = toml::Value::String(datetime.to_string()),
+        toml::Value::Array(items) => items.iter_mut().for_each(stringify_datetimes),
+        toml::Value::Table(table) => table
+            .iter_mut()
+            .for_each(|(_, item)| stringify_datetimes(item)),
+        _ => {}
+    }
+}
+
+/// 1-based line and column of byte `offset` in `text`
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+    (line, column)
+}
+
+/// Error at `line`:`column`, dropping the parser's own position relative to the block
+fn located(path: &Path, line: usize, column: usize, message: &str) -> GenWebBlogError {
+    let message = PARSER_POSITION.replace_all(message, "");
+    GenWebBlogError::frontmatter(path, line, column, message.trim())
+}
@@ -28,6 +28,7 @@ use crate::shared::generate_files::templates_articles::TEMPLATE_ARTICLES;
 use crate::shared::generate_files::templates_contact::TEMPLATE_CONTACT;
 use crate::shared::generate_files::templates_index::TEMPLATE_INDEX;
 use crate::shared::generate_files::templates_layout::TEMPLATE_LAYOUT;
+use crate::shared::generate_files::templates_page::TEMPLATE_PAGE;
 use crate::shared::generate_files::templates_policy::TEMPLATE_POLICY;
 use crate::shared::generate_files::templates_post::TEMPLATE_POST;
 use crate::shared::generate_files::templates_sitemap::TEMPLATE_SITEMAP;
@@ -99,6 +100,7 @@ pub fn create_files(is_full: bool) -> Result<()> {
         "source/pages/articles.html",
         "source/pages/contact.html",
         "source/pages/index.html",
+        "source/pages/page.html",
         "source/pages/policy.html",
         "source/pages/post.html",
         "source/pages/sitemap.html",
@@ -118,7 +120,12 @@ pub fn create_files(is_full: bool) -> Result<()> {
     ];
 
     let mut required_paths: Vec<&str> = Vec::with_capacity(
-        REQUIRED_PATHS_BASE.len() + if is_full { REQUIRED_PATHS_FULL_EXT.len() } else { 0 },
+        REQUIRED_PATHS_BASE.len()
+            + if is_full {
+                REQUIRED_PATHS_FULL_EXT.len()
+            } else {
+                0
+            },
     );
     required_paths.extend_from_slice(REQUIRED_PATHS_BASE);
     if is_full {
@@ -205,6 +212,7 @@ fn create_file_with_default_content(path: &Path, path_str: &str, app_info: &AppI
         "source/pages/sitemap.html" => TEMPLATE_SITEMAP.to_string(),
         "source/pages/policy.html" => TEMPLATE_POLICY.to_string(),
         "source/pages/about.html" => TEMPLATE_ABOUT.to_string(),
+        "source/pages/page.html" => TEMPLATE_PAGE.to_string(),
         "source/pages/articles.html" => TEMPLATE_ARTICLES.to_string(),
         "source/pages/contact.html" => TEMPLATE_CONTACT.to_string(),
         "source/components/header.html" => HEADER.to_string(),
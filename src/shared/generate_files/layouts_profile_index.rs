@@ -1,2 +1 @@
 pub const LAYOUT_PROFILE_INDEX: &str = include_str!("./templates/layouts_profile_index.html");
-
@@ -24,6 +24,7 @@ pub mod templates_articles;
 pub mod templates_contact;
 pub mod templates_index;
 pub mod templates_layout;
+pub mod templates_page;
 pub mod templates_policy;
 pub mod templates_post;
 pub mod templates_sitemap;
@@ -0,0 +1 @@
+pub const TEMPLATE_PAGE: &str = include_str!("./templates/templates_page.html");
@@ -92,10 +92,10 @@ pub fn register_unique_templates(
                         let relative_path = path.strip_prefix(root)?;
                         let mut template_name = relative_path
                             .to_string_lossy()
-                            .replace(std::path::MAIN_SEPARATOR, "/"); 
+                            .replace(std::path::MAIN_SEPARATOR, "/");
 
                         if let Some(stripped) = template_name.strip_suffix(&options.tpl_extension) {
-                            template_name = stripped.to_string(); 
+                            template_name = stripped.to_string();
                         }
 
                         if !registered.contains(&template_name) {
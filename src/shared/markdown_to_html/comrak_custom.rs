@@ -2,7 +2,7 @@ use comrak::{parse_document, Arena, Options, Plugins};
 use eyre::WrapErr;
 use std::io::BufWriter;
 
-use super::html_custom;
+use super::html_custom::{self, Heading};
 
 pub fn markdown_to_html(md: &str, options: &Options) -> String {
     markdown_to_html_with_plugins(md, options, &Plugins::default())
@@ -10,7 +10,7 @@ pub fn markdown_to_html(md: &str, options: &Options) -> String {
 
 pub fn markdown_to_html_with_plugins(md: &str, options: &Options, plugins: &Plugins) -> String {
     match render_markdown_bytes(md, options, plugins) {
-        Ok(bytes) => match String::from_utf8(bytes) {
+        Ok((bytes, _)) => match String::from_utf8(bytes) {
             Ok(s) => s,
             Err(_) => String::from("<!-- Invalid UTF-8 content -->"),
         },
@@ -21,14 +21,37 @@ pub fn markdown_to_html_with_plugins(md: &str, options: &Options, plugins: &Plug
     }
 }
 
-fn render_markdown_bytes(md: &str, options: &Options, plugins: &Plugins) -> eyre::Result<Vec<u8>> {
+/// Renders `md` and returns the headings it contains, for building a table of contents
+pub fn markdown_to_html_with_headings(md: &str, options: &Options) -> (String, Vec<Heading>) {
+    match render_markdown_bytes(md, options, &Plugins::default()) {
+        Ok((bytes, headings)) => match String::from_utf8(bytes) {
+            Ok(s) => (s, headings),
+            Err(_) => (String::from("<!-- Invalid UTF-8 content -->"), Vec::new()),
+        },
+        Err(e) => {
+            log::error!("markdown_to_html error: {e}");
+            (
+                String::from("<!-- Error formatting document -->"),
+                Vec::new(),
+            )
+        }
+    }
+}
+
+fn render_markdown_bytes(
+    md: &str,
+    options: &Options,
+    plugins: &Plugins,
+) -> eyre::Result<(Vec<u8>, Vec<Heading>)> {
     let arena = Arena::new();
     let root = parse_document(&arena, md, options);
     let mut bw = BufWriter::new(Vec::new());
 
-    html_custom::format_document_with_plugins(root, options, &mut bw, plugins)
+    let headings = html_custom::format_document_with_headings(root, options, &mut bw, plugins)
         .wrap_err("Failed to format document with plugins")?;
 
-    bw.into_inner()
-        .map_err(|e| eyre::eyre!("Failed to flush buffer: {}", e))
+    let bytes = bw
+        .into_inner()
+        .map_err(|e| eyre::eyre!("Failed to flush buffer: {}", e))?;
+    Ok((bytes, headings))
 }
@@ -65,6 +65,16 @@ pub fn format_document_with_plugins<'a>(
     output: &mut dyn Write,
     plugins: &Plugins,
 ) -> io::Result<()> {
+    format_document_with_headings(root, options, output, plugins).map(|_| ())
+}
+
+/// Like [`format_document_with_plugins`], also returning the headings in document order
+pub fn format_document_with_headings<'a>(
+    root: &'a AstNode<'a>,
+    options: &Options,
+    output: &mut dyn Write,
+    plugins: &Plugins,
+) -> io::Result<Vec<Heading>> {
     let mut writer = WriteWithLast {
         output,
         last_was_lf: Cell::new(true),
@@ -74,7 +84,15 @@ pub fn format_document_with_plugins<'a>(
     if f.footnote_ix > 0 {
         f.output.write_all(b"</ol>\n</section>\n")?;
     }
-    Ok(())
+    Ok(f.headings)
+}
+
+/// A rendered heading: its level, the `id` written by the anchorizer and its plain text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    pub level: u8,
+    pub id: String,
+    pub text: String,
 }
 
 struct WriteWithLast<'w> {
@@ -145,6 +163,7 @@ struct HtmlFormatter<'o, 'c> {
     output: &'o mut WriteWithLast<'o>,
     options: &'o Options<'c>,
     anchorizer: Anchorizer,
+    headings: Vec<Heading>,
     footnote_ix: u32,
     written_footnote_ix: u32,
     plugins: &'o Plugins<'o>,
@@ -312,6 +331,7 @@ where
             options,
             output,
             anchorizer: Anchorizer::new(),
+            headings: Vec::new(),
             footnote_ix: 0,
             written_footnote_ix: 0,
             plugins,
@@ -488,8 +508,8 @@ where
                         let mut text_content = Vec::with_capacity(20);
                         Self::collect_text(node, &mut text_content);
 
-                        let mut id = String::from_utf8_lossy(&text_content).to_string();
-                        id = self.anchorizer.anchorize(id);
+                        let text = String::from_utf8_lossy(&text_content).to_string();
+                        let id = self.anchorizer.anchorize(text.clone());
 
                         let unique_id = if let Some(ref prefix) = self.options.extension.header_ids
                         {
@@ -497,6 +517,11 @@ where
                         } else {
                             id.clone()
                         };
+                        self.headings.push(Heading {
+                            level: nch.level,
+                            id: unique_id.clone(),
+                            text: text.trim().to_string(),
+                        });
 
                         write!(self.output, "<h{} id=\"{}\"", nch.level, unique_id)?;
                         self.render_sourcepos(node)?;
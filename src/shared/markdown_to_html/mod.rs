@@ -1,6 +1,7 @@
 pub mod comrak_custom;
 pub mod ctype_custom;
 pub mod html_custom;
+pub mod options;
 pub mod scanners_custom;
 // pub mod alert;
 pub mod character_set;
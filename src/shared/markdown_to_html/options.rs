@@ -0,0 +1,69 @@
+use comrak::{ExtensionOptions, Options, ParseOptions, RenderOptions};
+
+use crate::app::{AppConfig, Markdown};
+
+/// comrak options from `[markdown]`, with a section's `markdown:` from `index.yml`
+/// laid over it. Without either, posts render as they always have: raw HTML,
+/// strikethrough, tables and footnotes on, everything else off.
+#[must_use]
+pub fn options(app: &AppConfig, section: Option<&Markdown>) -> Options<'static> {
+    let site = app.markdown.clone().unwrap_or_default();
+    let config = match section {
+        Some(section) => layered(&site, section),
+        None => site,
+    };
+    let on = |value: Option<bool>| value.unwrap_or(false);
+    let math = on(config.math);
+
+    Options {
+        extension: ExtensionOptions::builder()
+            // Heading ids are the anchors of the table of contents
+            .header_ids(String::new())
+            .strikethrough(config.strikethrough.unwrap_or(true))
+            .table(config.tables.unwrap_or(true))
+            .footnotes(config.footnotes.unwrap_or(true))
+            .autolink(on(config.autolink))
+            .tasklist(on(config.tasklists))
+            .description_lists(on(config.description_lists))
+            .superscript(on(config.superscript))
+            .subscript(on(config.subscript))
+            .math_dollars(math)
+            .math_code(math)
+            .alerts(on(config.alerts))
+            .wikilinks_title_after_pipe(on(config.wikilinks))
+            .underline(on(config.underline))
+            .spoiler(on(config.spoilers))
+            .build(),
+        parse: ParseOptions::builder()
+            .smart(on(config.smart_punctuation))
+            .build(),
+        render: RenderOptions::builder()
+            .unsafe_(config.unsafe_html.unwrap_or(true))
+            .hardbreaks(on(config.hard_breaks))
+            .figure_with_caption(on(config.figure_with_caption))
+            .build(),
+    }
+}
+
+/// `over` wherever it sets a value, `base` elsewhere
+fn layered(base: &Markdown, over: &Markdown) -> Markdown {
+    Markdown {
+        unsafe_html: over.unsafe_html.or(base.unsafe_html),
+        strikethrough: over.strikethrough.or(base.strikethrough),
+        tables: over.tables.or(base.tables),
+        footnotes: over.footnotes.or(base.footnotes),
+        autolink: over.autolink.or(base.autolink),
+        tasklists: over.tasklists.or(base.tasklists),
+        description_lists: over.description_lists.or(base.description_lists),
+        superscript: over.superscript.or(base.superscript),
+        subscript: over.subscript.or(base.subscript),
+        math: over.math.or(base.math),
+        alerts: over.alerts.or(base.alerts),
+        wikilinks: over.wikilinks.or(base.wikilinks),
+        underline: over.underline.or(base.underline),
+        spoilers: over.spoilers.or(base.spoilers),
+        smart_punctuation: over.smart_punctuation.or(base.smart_punctuation),
+        hard_breaks: over.hard_breaks.or(base.hard_breaks),
+        figure_with_caption: over.figure_with_caption.or(base.figure_with_caption),
+    }
+}
@@ -1,23 +1,31 @@
+pub mod bundle;
 pub mod cloudflare;
 pub mod command;
+pub mod constants;
+pub mod css;
+pub mod error;
+pub mod frontmatter;
+pub mod fs;
 pub mod generate_files;
+pub mod github;
 pub mod handlebars;
+pub mod language;
 pub mod markdown_to_html;
+pub mod pages;
+pub mod params;
+pub mod permalink;
 pub mod process_image;
 pub mod read_metadata_yml;
 pub mod robots;
 pub mod run_server;
 pub mod seo;
+pub mod summary;
+pub mod tailwind;
+pub mod template_manager;
+pub mod toc;
 pub mod update_version;
-pub mod utils;
-pub mod fs;
-pub mod css;
 pub mod url;
-pub mod verify_token;
-pub mod constants;
-pub mod github;
-pub mod error;
-pub mod language;
-pub mod template_manager;
-pub mod tailwind;
+pub mod utils;
 pub mod validation;
+pub mod verify_token;
+pub mod word_count;
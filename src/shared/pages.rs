@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::path::{Component, Path};
+
+use crate::app::AppConfig;
+use crate::constants::files::BUNDLE_INDEX;
+use crate::shared::frontmatter;
+use crate::shared::markdown_to_html::comrak_custom::markdown_to_html;
+use crate::shared::markdown_to_html::options::options;
+use crate::shared::read_metadata_yml::GenericYmlInfo;
+
+const MANIFEST_FILE: &str = "index.yml";
+
+/// Template of markdown pages that don't set `layout`
+pub const DEFAULT_LAYOUT: &str = "page";
+
+/// Whether a markdown file is a standalone page rather than a post: it lies outside
+/// any section, or its front matter sets `layout: page`. Every other markdown file in
+/// a section is a post, so one that lost its date is still reported as such.
+///
+/// # Errors
+///
+/// Returns an error if the front matter of an undated file in a section is invalid
+pub fn is_page(path: &Path) -> eyre::Result<bool> {
+    let Some(dir) = path.parent() else {
+        return Ok(false);
+    };
+    // A bundle post's `index.md`, named by its folder
+    if path.file_name().is_some_and(|name| name == BUNDLE_INDEX)
+        && dir
+            .parent()
+            .is_some_and(|section| section.join(MANIFEST_FILE).is_file())
+    {
+        return Ok(false);
+    }
+    if !dir.join(MANIFEST_FILE).is_file() {
+        return Ok(true);
+    }
+    let dated_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(has_date_prefix);
+    if dated_name {
+        return Ok(false);
+    }
+    let layout = frontmatter_fields(path)?.remove("layout");
+    Ok(layout.as_ref().and_then(serde_yaml::Value::as_str) == Some(DEFAULT_LAYOUT))
+}
+
+/// Whether the file's front matter sets `date` or `date_published`
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or its front matter is invalid
+pub fn has_frontmatter_date(path: &Path) -> eyre::Result<bool> {
+    let fields = frontmatter_fields(path)?;
+    Ok(["date", "date_published"]
+        .iter()
+        .any(|key| fields.get(*key).is_some_and(|v| !v.is_null())))
+}
+
+fn frontmatter_fields(path: &Path) -> eyre::Result<BTreeMap<String, serde_yaml::Value>> {
+    let contents = crate::shared::fs::read_file_to_string(path)?;
+    match frontmatter::split(path, &contents)? {
+        Some(block) => Ok(block.deserialize(path)?),
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+fn has_date_prefix(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    bytes.len() > 11
+        && bytes[..10].iter().enumerate().all(|(i, b)| {
+            if i == 4 || i == 7 {
+                *b == b'-'
+            } else {
+                b.is_ascii_digit()
+            }
+        })
+        && bytes[10] == b'-'
+}
+
+/// Language folder and page name of a page, from its path under the contents directory:
+/// `legal/privacy.md` is `legal/privacy`, `en/about.md` is `about` in `en`,
+/// and `legal/index.md` is `legal`
+#[must_use]
+pub fn page_name(contents_dir: &Path, path: &Path) -> (Option<String>, String) {
+    let relative = path.strip_prefix(contents_dir).unwrap_or(path);
+    let mut parts: Vec<String> = relative
+        .with_extension("")
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(part) => part.to_str().map(str::to_string),
+            _ => None,
+        })
+        .collect();
+
+    let lang = match parts.first() {
+        Some(first)
+            if parts.len() > 1
+                && first.len() == 2
+                && first.chars().all(|c| c.is_ascii_alphabetic()) =>
+        {
+            Some(parts.remove(0))
+        }
+        _ => None,
+    };
+    if parts.len() > 1 && parts.last().is_some_and(|last| last == "index") {
+        parts.pop();
+    }
+    (lang, parts.join("/"))
+}
+
+/// Read one markdown page, rendering its body into `content`
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or its front matter is invalid
+pub fn read_page(
+    contents_dir: &Path,
+    path: &Path,
+    app: &AppConfig,
+) -> eyre::Result<GenericYmlInfo> {
+    let contents = crate::shared::fs::read_file_to_string(path)?;
+    let (mut page, body): (GenericYmlInfo, &str) = frontmatter::parse(path, &contents)?;
+    let (lang, name) = page_name(contents_dir, path);
+    page.page_name = name;
+    if lang.is_some() {
+        page.lang = lang;
+    }
+    if page.layout.is_none() {
+        page.layout = Some(DEFAULT_LAYOUT.to_string());
+    }
+    page.content = Some(markdown_to_html(body, &options(app, None)));
+    Ok(page)
+}
+
+/// Every markdown page under `contents_dir`
+///
+/// # Errors
+///
+/// Returns an error for the first page that cannot be read
+pub fn scan(contents_dir: &Path, app: &AppConfig) -> eyre::Result<Vec<GenericYmlInfo>> {
+    let mut pages = Vec::new();
+    scan_recursive(contents_dir, contents_dir, app, &mut pages)?;
+    pages.sort_by(|a, b| a.page_name.cmp(&b.page_name));
+    Ok(pages)
+}
+
+fn scan_recursive(
+    contents_dir: &Path,
+    dir: &Path,
+    app: &AppConfig,
+    pages: &mut Vec<GenericYmlInfo>,
+) -> eyre::Result<()> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            scan_recursive(contents_dir, &path, app, pages)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some("md") && is_page(&path)? {
+            pages.push(read_page(contents_dir, &path, app)?);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::app::AppConfig;
+use crate::generator::schedule::parse_date;
+
+/// Custom front matter fields, exposed to templates as `post.params`,
+/// `article.params` and `page.params`
+pub type Params = serde_json::Map<String, Value>;
+
+/// Value type of a param in `[params.schema]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamType {
+    String,
+    Number,
+    Integer,
+    Boolean,
+    /// RFC 3339 or `YYYY-MM-DD`
+    Date,
+    Array,
+    Object,
+}
+
+impl ParamType {
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Number => value.is_number(),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Boolean => value.is_boolean(),
+            Self::Date => value.as_str().and_then(parse_date).is_some(),
+            Self::Array => value.is_array(),
+            Self::Object => value.is_object(),
+        }
+    }
+}
+
+impl fmt::Display for ParamType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::String => "a string",
+            Self::Number => "a number",
+            Self::Integer => "an integer",
+            Self::Boolean => "true or false",
+            Self::Date => "a date (RFC 3339 or YYYY-MM-DD)",
+            Self::Array => "a list",
+            Self::Object => "a map",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Schema entry: `difficulty = "string"`, or
+/// `difficulty = { type = "string", values = ["beginner", "advanced"] }`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ParamSpec {
+    Type(ParamType),
+    Detailed {
+        #[serde(rename = "type")]
+        kind: ParamType,
+        /// Allowed values
+        #[serde(default)]
+        values: Option<Vec<Value>>,
+    },
+}
+
+impl ParamSpec {
+    fn kind(&self) -> ParamType {
+        match self {
+            Self::Type(kind) | Self::Detailed { kind, .. } => *kind,
+        }
+    }
+
+    fn values(&self) -> Option<&[Value]> {
+        match self {
+            Self::Type(_) => None,
+            Self::Detailed { values, .. } => values.as_deref(),
+        }
+    }
+}
+
+/// Unknown front matter keys with the `params:` map laid over them
+#[must_use]
+pub fn collect<V: Serialize>(extra: &BTreeMap<String, V>, params: Option<Params>) -> Params {
+    let mut collected: Params = extra
+        .iter()
+        .filter_map(|(key, value)| Some((key.clone(), serde_json::to_value(value).ok()?)))
+        .collect();
+    collected.extend(params.unwrap_or_default());
+    collected
+}
+
+/// Check `params` against `[params.schema]`; params the schema doesn't name are not checked
+///
+/// # Errors
+///
+/// Returns a message naming every param of the wrong type or outside its allowed values
+pub fn validate(params: &Params, app: &AppConfig) -> Result<(), String> {
+    let Some(schema) = app.params.as_ref().and_then(|p| p.schema.as_ref()) else {
+        return Ok(());
+    };
+
+    let problems: Vec<String> = schema
+        .iter()
+        .filter_map(|(name, spec)| {
+            let value = params.get(name)?;
+            if value.is_null() {
+                return None;
+            }
+            if !spec.kind().matches(value) {
+                return Some(format!("`{name}` should be {}, found {value}", spec.kind()));
+            }
+            match spec.values() {
+                Some(allowed) if !allowed.contains(value) => {
+                    let allowed: Vec<String> = allowed.iter().map(Value::to_string).collect();
+                    Some(format!(
+                        "`{name}` should be one of {}, found {value}",
+                        allowed.join(", ")
+                    ))
+                }
+                _ => None,
+            }
+        })
+        .collect();
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems.join("; "))
+    }
+}
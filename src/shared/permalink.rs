@@ -0,0 +1,79 @@
+//! Post URLs from `[paths] permalink` patterns such as `/{section}/{year}/{month}/{slug}/`
+
+use eyre::eyre;
+
+use crate::shared::url::slugify;
+
+/// Values a permalink pattern can reference
+#[derive(Debug, Clone, Copy)]
+pub struct PermalinkParts<'a> {
+    /// Section directory relative to the contents root (`seo`, `web-optimization`), empty for the root
+    pub section: &'a str,
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub slug: &'a str,
+    pub category: Option<&'a str>,
+}
+
+/// Expand `pattern` into a site path starting with `/`.
+///
+/// A pattern ending in `/` gives a pretty URL (`.../slug/`, written as `slug/index.html`),
+/// one ending in `.html` a file URL; any other ending follows `use_directory_structure`.
+/// Empty segments (e.g. `{section}` for root posts) are dropped.
+///
+/// # Errors
+///
+/// Returns an error for an unknown `{token}` or an unclosed brace
+pub fn expand(pattern: &str, parts: &PermalinkParts, use_directory: bool) -> eyre::Result<String> {
+    let mut expanded = String::with_capacity(pattern.len() + parts.slug.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| eyre!("Unclosed '{{' in permalink pattern \"{pattern}\""))?
+            + start;
+        let value = match &rest[start + 1..end] {
+            "section" => parts.section.to_string(),
+            "year" => format!("{:04}", parts.year),
+            "month" => format!("{:02}", parts.month),
+            "day" => format!("{:02}", parts.day),
+            "slug" => parts.slug.to_string(),
+            "category" => parts.category.map(slugify).unwrap_or_default(),
+            token => {
+                return Err(eyre!(
+                    "Unknown token {{{token}}} in permalink pattern \"{pattern}\", expected one of \
+                     {{section}}, {{year}}, {{month}}, {{day}}, {{slug}}, {{category}}"
+                ))
+            }
+        };
+        expanded.push_str(&value);
+        rest = &rest[end + 1..];
+    }
+    expanded.push_str(rest);
+
+    let segments: Vec<&str> = expanded.split('/').filter(|s| !s.is_empty()).collect();
+    let path = format!("/{}", segments.join("/"));
+
+    Ok(if expanded.ends_with('/') {
+        format!("{}/", path.trim_end_matches('/'))
+    } else if path.ends_with(".html") {
+        path
+    } else if use_directory {
+        format!("{path}/")
+    } else {
+        format!("{path}.html")
+    })
+}
+
+/// File a post URL is written to, relative to the build (or language) directory
+#[must_use]
+pub fn output_path(url: &str) -> String {
+    let path = url.trim_start_matches('/');
+    if path.is_empty() || path.ends_with('/') {
+        format!("{path}index.html")
+    } else {
+        path.to_string()
+    }
+}
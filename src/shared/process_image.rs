@@ -1,10 +1,10 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use image::ImageFormat;
+use std::fs as stdfs;
 use std::fs::metadata;
 use std::path::Path;
 use std::process::Command;
-use std::fs as stdfs;
-use base64::engine::general_purpose::STANDARD;
-use base64::Engine;
 
 use crate::shared::command::install_required_tool::install_required_tool;
 
@@ -31,14 +31,23 @@ fn output_base_from_path(path_str: &str) -> Result<String, String> {
         .parent()
         .ok_or_else(|| "Invalid parent directory".to_string())?
         .join(file_name);
-    base
-        .to_str()
+    base.to_str()
         .map(|s| s.to_string())
         .ok_or_else(|| "Invalid output path encoding".to_string())
 }
 
 fn process_image_internal(input_image_path: &str, is_logo: bool) -> Result<String, String> {
     let url_public = to_public_path(input_image_path)?;
+    process_image_at(&url_public, input_image_path, is_logo)
+}
+
+/// Resize the image file at `url_public`; `input_image_path` is the URL the site serves it at
+fn process_image_at(
+    url_public: &str,
+    input_image_path: &str,
+    is_logo: bool,
+) -> Result<String, String> {
+    let url_public = url_public.to_string();
 
     if metadata(&url_public).is_err() {
         println!("│  ⚠️ Image file does not exist: {url_public}");
@@ -171,6 +180,16 @@ pub fn process_image(input_image_path: &str) -> Result<String, String> {
     process_image_internal(input_image_path, false)
 }
 
+/// Process an image that lives outside `public/`, such as a page bundle file.
+///
+/// The resized variants are written next to `source`; the returned base is built from `url`.
+pub fn process_image_file(source: &Path, url: &str) -> Result<String, String> {
+    let source = source
+        .to_str()
+        .ok_or_else(|| "Invalid path encoding".to_string())?;
+    process_image_at(source, url, false)
+}
+
 pub fn process_logo(input_image_path: &str) -> Result<String, String> {
     // For the `logo` command we only generate favicons (do not run full resize pipeline).
     match generate_favicons(input_image_path) {
@@ -218,7 +237,11 @@ fn resize_image(
 
     // Build ImageMagick command. For PNG outputs (logos) we must preserve alpha and don't set JPEG quality.
     let mut magick_cmd = Command::new("magick");
-    magick_cmd.arg(input_path).arg("-resize").arg(format!("{width}x")).arg("-strip");
+    magick_cmd
+        .arg(input_path)
+        .arg("-resize")
+        .arg(format!("{width}x"))
+        .arg("-strip");
     if output_path.to_lowercase().ends_with(".png") {
         // Preserve alpha channel: do not set -quality
         magick_cmd.arg(output_path);
@@ -439,12 +462,7 @@ pub fn generate_favicons(input_path: &str) -> Result<(), String> {
         // Create 64x64 PNG
         execute_command(
             "magick",
-            &[
-                &working_image_path,
-                "-resize",
-                "64x64",
-                tmp_png_str,
-            ],
+            &[&working_image_path, "-resize", "64x64", tmp_png_str],
             "create temporary 64x64 png",
         )?;
 
@@ -464,8 +482,12 @@ pub fn generate_favicons(input_path: &str) -> Result<(), String> {
 
         // mask-icon.svg - use same embedded PNG (consumers expect mask-icon.svg presence)
         let mask_svg_path = output_dir.join("mask-icon.svg");
-        stdfs::write(&mask_svg_path, stdfs::read(&favicon_svg_path).map_err(|e| format!("Failed to read generated favicon.svg for mask-icon: {e}"))?)
-            .map_err(|e| format!("Failed to write mask-icon.svg: {e}"))?;
+        stdfs::write(
+            &mask_svg_path,
+            stdfs::read(&favicon_svg_path)
+                .map_err(|e| format!("Failed to read generated favicon.svg for mask-icon: {e}"))?,
+        )
+        .map_err(|e| format!("Failed to write mask-icon.svg: {e}"))?;
         println!("│  ✅ Created mask-icon.svg (embedded PNG)");
 
         // Clean up temporary PNG
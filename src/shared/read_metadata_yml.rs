@@ -1,5 +1,6 @@
 use serde::Deserialize;
-use serde_yaml;
+
+use crate::shared::params::Params;
 use std::fs;
 use std::path::Path;
 
@@ -42,6 +43,15 @@ pub struct GenericYmlInfo {
     pub author_url: Option<String>,
     #[serde(default)]
     pub author_email: Option<String>,
+    /// Posts per listing page for this page, overriding `[pagination] page_size`
+    #[serde(default)]
+    pub paginate: Option<usize>,
+    /// Custom fields for templates, as `page.params`
+    #[serde(default)]
+    pub params: Params,
+    /// Rendered body of a markdown page, as `page.content`
+    #[serde(skip)]
+    pub content: Option<String>,
 }
 
 pub fn read_yaml_metadata<T: for<'de> Deserialize<'de>>(
@@ -57,10 +67,10 @@ pub fn read_yaml_metadata<T: for<'de> Deserialize<'de>>(
         }
     };
 
-    match serde_yaml::from_str(&yaml_content) {
+    match crate::shared::frontmatter::parse_yaml(Path::new(file_name), &yaml_content, 1) {
         Ok(val) => Ok(val),
         Err(err) => {
-            eprintln!("│  ❌ Failed to parse YAML in {file_name}: {err}");
+            eprintln!("│  ❌ Failed to parse YAML: {err}");
             Err(Box::new(err))
         }
     }
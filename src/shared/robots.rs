@@ -1,4 +1,5 @@
 use crate::app::AppConfig;
+use crate::generator::sitemap::SitemapBuilder;
 use eyre::Result;
 use std::fs;
 use std::path::Path;
@@ -10,23 +11,16 @@ Allow: /
 "
     .to_string();
 
-    let contents_dir = Path::new("contents");
     let app_domain = &app.app_info.app_domain;
     // Ensure no trailing slash on domain
     let app_domain = app_domain.trim_end_matches('/');
 
-    if contents_dir.exists() && contents_dir.is_dir() {
-        for entry in fs::read_dir(contents_dir)? {
-            let entry = entry?;
-            if entry.path().is_dir() {
-                let folder_name = entry.file_name().to_string_lossy().to_string();
-                let sitemap_path = format!("{app_domain}/{folder_name}/sitemap.xml");
-                robots_txt_content.push_str(&format!("Sitemap: {sitemap_path}\n"));
-            }
-        }
-    }
-
+    // Sitemap index first, followed by the per-language sitemaps it references
     robots_txt_content.push_str(&format!("Sitemap: {app_domain}/sitemap.xml\n"));
+    for lang_code in &app.languages.installed_languages {
+        let sitemap_path = SitemapBuilder::sitemap_path_for_language(app, lang_code);
+        robots_txt_content.push_str(&format!("Sitemap: {app_domain}/{sitemap_path}\n"));
+    }
 
     let robots_txt_path = Path::new("public/robots.txt");
 
@@ -1,6 +1,6 @@
-mod local_ipaddress;
 mod cert;
 mod display;
+mod local_ipaddress;
 mod run;
 
 pub use run::run;
@@ -0,0 +1,51 @@
+//! Post summaries: the text before `<!--more-->`, or the opening of the post
+
+use crate::constants::content::SUMMARY_SEPARATOR;
+use crate::shared::utils::{extract_excerpt, strip_html_tags};
+
+/// Split markdown at the first `<!--more-->` line (inner spaces allowed, e.g. `<!-- more -->`).
+/// A separator inside a fenced code block is shown as code, not split at.
+///
+/// Returns the text before and after the separator line.
+#[must_use]
+pub fn split_at_separator(markdown: &str) -> Option<(&str, &str)> {
+    let separator: String = SUMMARY_SEPARATOR.split_whitespace().collect();
+    let mut offset = 0;
+    let mut fence: Option<&str> = None;
+    for line in markdown.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+        match (fence, marker) {
+            (None, Some(marker)) => fence = Some(marker),
+            (Some(open), Some(marker)) if open == marker => fence = None,
+            _ => {}
+        }
+        let compact: String = line.split_whitespace().collect();
+        if fence.is_none() && compact == separator {
+            return Some((&markdown[..offset], &markdown[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Plain text of a rendered summary with whitespace collapsed
+#[must_use]
+pub fn plain_text(html: &str) -> String {
+    strip_html_tags(html)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Summary of rendered post HTML cut to `length` characters, as plain text and as a paragraph
+#[must_use]
+pub fn auto_summary(html: &str, length: usize) -> (String, String) {
+    let summary = extract_excerpt(&plain_text(html), length);
+    let summary_html = if summary.is_empty() {
+        String::new()
+    } else {
+        format!("<p>{}</p>", html_escape::encode_text(&summary))
+    };
+    (summary, summary_html)
+}
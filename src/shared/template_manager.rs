@@ -1,9 +1,9 @@
 use eyre::Result;
+use log::info;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
 use std::fs;
-use log::info;
+use std::path::Path;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ThemeConfig {
@@ -503,7 +503,10 @@ a:hover {{
             custom_theme.author = "User".to_string();
 
             let config_content = toml::to_string_pretty(&custom_theme)?;
-            crate::shared::fs::write_file(format!("{theme_dir}/theme.toml"), config_content.as_bytes())?;
+            crate::shared::fs::write_file(
+                format!("{theme_dir}/theme.toml"),
+                config_content.as_bytes(),
+            )?;
 
             // Add to themes collection
             self.themes.insert(name.to_string(), custom_theme);
@@ -0,0 +1,104 @@
+//! Table of contents from the headings collected while rendering markdown
+
+use serde::Serialize;
+
+use crate::app::AppConfig;
+use crate::constants::toc::{DEFAULT_MAX_LEVEL, DEFAULT_MIN_LEVEL, MIN_HEADINGS};
+use crate::shared::markdown_to_html::html_custom::{escape, Heading};
+
+/// One heading in the table of contents, with the deeper headings under it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TocEntry {
+    pub id: String,
+    pub text: String,
+    pub level: u8,
+    pub children: Vec<TocEntry>,
+}
+
+/// Heading levels listed in the table of contents, from `[toc]`, or `None` when disabled
+#[must_use]
+pub fn levels(app: &AppConfig) -> Option<(u8, u8)> {
+    let toc = app.toc.as_ref();
+    if !toc.and_then(|t| t.enabled).unwrap_or(true) {
+        return None;
+    }
+    let min = toc
+        .and_then(|t| t.min_level)
+        .unwrap_or(DEFAULT_MIN_LEVEL)
+        .clamp(1, 6);
+    let max = toc
+        .and_then(|t| t.max_level)
+        .unwrap_or(DEFAULT_MAX_LEVEL)
+        .clamp(min, 6);
+    Some((min, max))
+}
+
+/// Nest the headings between `min_level` and `max_level`.
+///
+/// A heading becomes a child of the closest earlier heading with a lower level, so
+/// skipped levels (`<h2>` then `<h4>`) still nest. Returns nothing when fewer than
+/// `MIN_HEADINGS` headings are listed.
+#[must_use]
+pub fn build(headings: &[Heading], min_level: u8, max_level: u8) -> Vec<TocEntry> {
+    let listed: Vec<&Heading> = headings
+        .iter()
+        .filter(|h| (min_level..=max_level).contains(&h.level) && !h.text.is_empty())
+        .collect();
+    if listed.len() < MIN_HEADINGS {
+        return Vec::new();
+    }
+
+    // Open entries from the outermost to the current one
+    let mut stack: Vec<TocEntry> = Vec::new();
+    let mut roots = Vec::new();
+    for heading in listed {
+        while stack.last().is_some_and(|open| open.level >= heading.level) {
+            close(&mut stack, &mut roots);
+        }
+        stack.push(TocEntry {
+            id: heading.id.clone(),
+            text: heading.text.clone(),
+            level: heading.level,
+            children: Vec::new(),
+        });
+    }
+    while !stack.is_empty() {
+        close(&mut stack, &mut roots);
+    }
+    roots
+}
+
+fn close(stack: &mut Vec<TocEntry>, roots: &mut Vec<TocEntry>) {
+    if let Some(entry) = stack.pop() {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(entry),
+            None => roots.push(entry),
+        }
+    }
+}
+
+/// Nested `<ol>` linking to each heading, empty when there are no entries
+#[must_use]
+pub fn render(entries: &[TocEntry]) -> String {
+    let mut html = Vec::new();
+    write_list(&mut html, entries);
+    String::from_utf8(html).unwrap_or_default()
+}
+
+fn write_list(html: &mut Vec<u8>, entries: &[TocEntry]) {
+    if entries.is_empty() {
+        return;
+    }
+    html.extend_from_slice(b"<ol>");
+    for entry in entries {
+        html.extend_from_slice(b"<li><a href=\"#");
+        // Writing to a Vec cannot fail
+        let _ = escape(html, entry.id.as_bytes());
+        html.extend_from_slice(b"\">");
+        let _ = escape(html, entry.text.as_bytes());
+        html.extend_from_slice(b"</a>");
+        write_list(html, &entry.children);
+        html.extend_from_slice(b"</li>");
+    }
+    html.extend_from_slice(b"</ol>");
+}
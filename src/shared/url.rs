@@ -45,3 +45,50 @@ pub fn file_url(out_directory: &PathBuf, path: &Path) -> String {
 
     format!("file:///{out}/{p}")
 }
+
+/// Turn a free-form label (tag, category, title) into a URL path segment.
+/// Letters and combining marks from any script are kept so Thai labels stay readable.
+pub fn slugify(label: &str) -> String {
+    use unicode_categories::UnicodeCategories;
+
+    let mut slug = String::with_capacity(label.len());
+    for c in label.trim().to_lowercase().chars() {
+        if c.is_letter() || c.is_mark() || c.is_number() {
+            slug.push(c);
+        } else if (c.is_whitespace() || c == '-' || c == '_' || c == '/')
+            && !slug.is_empty()
+            && !slug.ends_with('-')
+        {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Percent-encode a URL path per RFC 3986, one segment at a time: `/tags/คู่มือ/`
+/// becomes `/tags/%E0%B8%84.../`. `/` is kept, and existing `%XX` escapes are not encoded twice.
+pub fn encode_path(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut encoded = String::with_capacity(path.len());
+    for (i, &b) in bytes.iter().enumerate() {
+        let escaped = b == b'%'
+            && bytes.len() > i + 2
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit();
+        if b.is_ascii_alphanumeric() || b"-._~/!$&'()*+,;=:@".contains(&b) || escaped {
+            encoded.push(b as char);
+        } else {
+            encoded.push_str(&format!("%{b:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Prefix a language-independent path with `/<lang>` unless it is the default language.
+pub fn localized_path(default_language: &str, language_code: &str, path: &str) -> String {
+    if language_code == default_language {
+        path.to_string()
+    } else {
+        format!("/{language_code}{path}")
+    }
+}
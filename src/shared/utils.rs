@@ -15,6 +15,7 @@ pub use crate::shared::url::{file_url, get_default_post_image, get_image};
 
 use std::path::Path;
 use std::time::Duration;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Serialize a path as a string and ensure it has a single trailing slash when non-empty.
 pub fn add_postfix_slash<S>(path: &Path, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -88,19 +89,37 @@ pub fn calculate_reading_time(word_count: usize, wpm: u32) -> u32 {
     ((word_count as f64 / wpm as f64).ceil() as u32).max(1)
 }
 
-/// Extract excerpt from content using character-aware truncation so multi-byte UTF-8 isn't split.
+/// Plain text of an HTML fragment: tags become spaces, entities are decoded
+#[must_use]
+pub fn strip_html_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    html_escape::decode_html_entities(&text).into_owned()
+}
+
+/// Extract excerpt from content, counting grapheme clusters so Thai vowel and tone marks
+/// stay with their consonant.
 pub fn extract_excerpt(content: &str, max_length: usize) -> String {
-    let char_count = content.chars().count();
-    if char_count <= max_length {
-        content.to_string()
+    let Some((cut, _)) = content.grapheme_indices(true).nth(max_length) else {
+        return content.to_string();
+    };
+    let truncated = &content[..cut];
+    if let Some(last_space_idx) = truncated.rfind(' ') {
+        let prefix = &truncated[..last_space_idx];
+        format!("{prefix}...")
     } else {
-        let truncated: String = content.chars().take(max_length).collect();
-        if let Some(last_space_idx) = truncated.rfind(' ') {
-            let prefix = &truncated[..last_space_idx];
-            format!("{prefix}...")
-        } else {
-            format!("{truncated}...")
-        }
+        format!("{truncated}...")
     }
 }
 
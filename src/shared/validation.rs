@@ -1,20 +1,95 @@
 use eyre::{Result, WrapErr};
+use log::{info, warn};
+use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
-use log::{info, warn};
+
+use crate::app::AppConfig;
+use crate::constants::paths::PUBLIC_DIR;
+use crate::constants::validation::{
+    FORBIDDEN_FILENAME_CHARS, MAX_FILENAME_LENGTH, MAX_IMAGE_HEIGHT, MAX_IMAGE_SIZE_KB,
+    MAX_IMAGE_WIDTH,
+};
+
+static MARKDOWN_IMAGE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"!\[([^\]]*)\]\(\s*<?([^\s)>]+)").expect("valid regex"));
+static HTML_IMAGE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)<img\b[^>]*>").expect("valid regex"));
+static HTML_SRC: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)\bsrc\s*=\s*["']([^"']*)["']"#).expect("valid regex"));
+static HTML_ALT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)\balt\s*=\s*["']([^"']*)["']"#).expect("valid regex"));
+static INLINE_CODE: Lazy<Regex> = Lazy::new(|| Regex::new(r"`[^`]*`").expect("valid regex"));
+
+/// How a broken rule is reported; `error` fails the build in strict mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    Off,
+    #[serde(alias = "warning")]
+    Warn,
+    Error,
+}
+
+/// Severity of each validation rule, set under `[validation.severity]`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Severities {
+    /// `YYYY-MM-DD-kebab-case.md` post filenames
+    pub filename_format: RuleSeverity,
+    pub forbidden_chars: RuleSeverity,
+    /// Images in a format outside `allowed_image_formats`
+    pub image_format: RuleSeverity,
+    pub image_size: RuleSeverity,
+    pub image_dimensions: RuleSeverity,
+    /// Images in the post body without alt text
+    pub alt_text: RuleSeverity,
+    pub banned_words: RuleSeverity,
+    pub slug_length: RuleSeverity,
+}
+
+impl Default for Severities {
+    fn default() -> Self {
+        Self {
+            filename_format: RuleSeverity::Error,
+            forbidden_chars: RuleSeverity::Error,
+            image_format: RuleSeverity::Warn,
+            image_size: RuleSeverity::Warn,
+            image_dimensions: RuleSeverity::Warn,
+            alt_text: RuleSeverity::Warn,
+            banned_words: RuleSeverity::Error,
+            slug_length: RuleSeverity::Warn,
+        }
+    }
+}
 
 /// File validation configuration focused on markdown files
 #[derive(Debug, Clone)]
 pub struct ValidationConfig {
     pub enabled: bool,
+    #[deprecated(note = "set `severity.filename_format` to `RuleSeverity::Off` instead")]
     pub check_markdown_filename_format: bool,
+    /// Errors fail the build instead of being reported
+    pub strict: bool,
     pub allowed_image_formats: HashSet<String>,
     pub forbidden_chars: Vec<char>,
+    pub max_image_size_kb: u64,
+    pub max_image_width: u32,
+    pub max_image_height: u32,
+    /// Words or phrases that must not appear in a post, matched case-insensitively
+    pub banned_words: Vec<String>,
+    pub max_slug_length: usize,
+    pub severity: Severities,
+    /// Where images with a site path such as `/images/a.webp` are looked up
+    pub public_dir: PathBuf,
 }
 
 impl Default for ValidationConfig {
+    #[allow(deprecated)]
     fn default() -> Self {
         let mut allowed_image_formats = HashSet::new();
         allowed_image_formats.insert("webp".to_string());
@@ -27,9 +102,63 @@ impl Default for ValidationConfig {
         Self {
             enabled: true,
             check_markdown_filename_format: true,
+            strict: false,
             allowed_image_formats,
-            forbidden_chars: vec!['<', '>', ':', '"', '|', '?', '*', ' '],
+            forbidden_chars: FORBIDDEN_FILENAME_CHARS.to_vec(),
+            max_image_size_kb: MAX_IMAGE_SIZE_KB,
+            max_image_width: MAX_IMAGE_WIDTH,
+            max_image_height: MAX_IMAGE_HEIGHT,
+            banned_words: Vec::new(),
+            max_slug_length: MAX_FILENAME_LENGTH,
+            severity: Severities::default(),
+            public_dir: PathBuf::from(PUBLIC_DIR),
+        }
+    }
+}
+
+impl ValidationConfig {
+    /// Defaults overridden by the `[validation]` section of `app.toml`
+    #[must_use]
+    pub fn from_app(app: &AppConfig) -> Self {
+        let mut config = Self::default();
+        if let Some(public_dir) = app.paths.as_ref().and_then(|p| p.public_dir.as_ref()) {
+            config.public_dir = PathBuf::from(public_dir);
+        }
+        let Some(section) = app.validation.as_ref() else {
+            return config;
+        };
+
+        if let Some(enabled) = section.enabled {
+            config.enabled = enabled;
+        }
+        if let Some(strict) = section.strict {
+            config.strict = strict;
+        }
+        if let Some(formats) = &section.allowed_image_formats {
+            config.allowed_image_formats = formats.iter().map(|f| f.to_lowercase()).collect();
+        }
+        if let Some(chars) = &section.forbidden_chars {
+            config.forbidden_chars.clone_from(chars);
+        }
+        if let Some(size) = section.max_image_size_kb {
+            config.max_image_size_kb = size;
         }
+        if let Some(width) = section.max_image_width {
+            config.max_image_width = width;
+        }
+        if let Some(height) = section.max_image_height {
+            config.max_image_height = height;
+        }
+        if let Some(words) = &section.banned_words {
+            config.banned_words.clone_from(words);
+        }
+        if let Some(length) = section.max_slug_length {
+            config.max_slug_length = length;
+        }
+        if let Some(severity) = &section.severity {
+            config.severity = severity.clone();
+        }
+        config
     }
 }
 
@@ -43,8 +172,25 @@ impl FileValidator {
         Self { config }
     }
 
-    /// Validate a single file - focused on markdown filename format
+    #[must_use]
+    pub fn config(&self) -> &ValidationConfig {
+        &self.config
+    }
+
+    /// Validate a single file: the markdown filename format, then the images and
+    /// words of its content
     pub fn validate_file(&self, file_path: &Path) -> Result<ValidationResult> {
+        self.validate(file_path, true)
+    }
+
+    /// Validate a markdown page: like [`Self::validate_file`], but pages are named
+    /// freely so the filename format is not checked
+    pub fn validate_page(&self, file_path: &Path) -> Result<ValidationResult> {
+        self.validate(file_path, false)
+    }
+
+    #[allow(deprecated)]
+    fn validate(&self, file_path: &Path, check_filename: bool) -> Result<ValidationResult> {
         if !self.config.enabled {
             return Ok(ValidationResult::success());
         }
@@ -57,9 +203,14 @@ impl FileValidator {
             return Ok(result);
         }
 
-        // Validate markdown filename format
-        if self.config.check_markdown_filename_format && self.is_markdown_file(file_path) {
-            self.validate_markdown_filename_format(file_path, &mut result)?;
+        if self.is_markdown_file(file_path) {
+            if check_filename
+                && self.config.check_markdown_filename_format
+                && self.config.severity.filename_format != RuleSeverity::Off
+            {
+                self.validate_markdown_filename_format(file_path, &mut result)?;
+            }
+            self.validate_content(file_path, &mut result)?;
         }
 
         Ok(result)
@@ -80,7 +231,9 @@ impl FileValidator {
         file_path: &Path,
         result: &mut ValidationResult,
     ) -> Result<()> {
-        let filename = file_path
+        // A bundle's index.md is named by its directory
+        let named_path = crate::shared::bundle::bundle_dir(file_path).unwrap_or(file_path);
+        let filename = named_path
             .file_name()
             .and_then(|n| n.to_str())
             .ok_or_else(|| eyre::eyre!("Invalid filename"))?;
@@ -92,9 +245,19 @@ impl FileValidator {
         let date_kebab_regex = Regex::new(r"^\d{4}-\d{2}-\d{2}-[a-z0-9]+(-[a-z0-9]+)*$")
             .wrap_err("Failed to compile markdown filename regex")?;
 
+        let undated_kebab_regex = Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$")
+            .wrap_err("Failed to compile markdown filename regex")?;
+
         if !date_kebab_regex.is_match(name_without_ext) {
-            result.add_error(format!(
-                "Invalid markdown filename format: '{filename}'. Expected format: 'YYYY-MM-DD-kebab-case.md' (e.g., '2025-04-26-what-is-seo-beginners-guide.md')"
+            // Undated names are fine when the front matter carries the date
+            if undated_kebab_regex.is_match(name_without_ext)
+                && crate::shared::pages::has_frontmatter_date(file_path)?
+            {
+                self.check_slug_length(name_without_ext, filename, result);
+                return Ok(());
+            }
+            result.add(self.config.severity.filename_format, None, format!(
+                "Invalid markdown filename format: '{filename}'. Expected format: 'YYYY-MM-DD-kebab-case.md' (e.g., '2025-04-26-what-is-seo-beginners-guide.md'), or 'kebab-case.md' with `date` in the front matter"
             ));
         } else {
             // Additional validation: check date validity
@@ -110,19 +273,14 @@ impl FileValidator {
             let slug_part = name_without_ext.get(11..).unwrap_or(""); // everything after YYYY-MM-DD-
             for &forbidden_char in &self.config.forbidden_chars {
                 if slug_part.contains(forbidden_char) {
-                    result.add_error(format!(
+                    result.add(self.config.severity.forbidden_chars, None, format!(
                         "Filename contains forbidden character '{forbidden_char}' in slug part: {filename}"
                     ));
                 }
             }
 
             // Check slug length (not too long)
-            if slug_part.len() > 80 {
-                result.add_warning(format!(
-                    "Filename slug is quite long ({} chars): {}. Consider shortening for better readability.",
-                    slug_part.len(), filename
-                ));
-            }
+            self.check_slug_length(slug_part, filename, result);
 
             // Check for consecutive hyphens
             if slug_part.contains("--") {
@@ -135,6 +293,188 @@ impl FileValidator {
         Ok(())
     }
 
+    fn check_slug_length(&self, slug: &str, filename: &str, result: &mut ValidationResult) {
+        if slug.len() > self.config.max_slug_length {
+            result.add(
+                self.config.severity.slug_length,
+                None,
+                format!(
+                    "Filename slug is quite long ({} chars, limit {}): {filename}. Consider shortening for better readability.",
+                    slug.len(),
+                    self.config.max_slug_length
+                ),
+            );
+        }
+    }
+
+    /// Check the images and words of a markdown file
+    fn validate_content(&self, file_path: &Path, result: &mut ValidationResult) -> Result<()> {
+        let contents = crate::shared::fs::read_file_to_string(file_path)?;
+        let frontmatter = crate::shared::frontmatter::split(file_path, &contents)
+            .ok()
+            .flatten();
+        let bundle_dir = crate::shared::bundle::bundle_dir(file_path);
+
+        // Front matter `image`, then images in the body outside code
+        if let Some(block) = &frontmatter {
+            let image = block
+                .deserialize::<std::collections::BTreeMap<String, serde_yaml::Value>>(file_path)
+                .ok()
+                .and_then(|fields| fields.get("image")?.as_str().map(str::to_string));
+            if let Some(image) = image {
+                self.check_image(&image, block.line_of("image"), bundle_dir, result);
+            }
+        }
+        let body_start = frontmatter.map_or(1, |block| {
+            let offset = block.body.as_ptr() as usize - contents.as_ptr() as usize;
+            contents[..offset].matches('\n').count() + 1
+        });
+        let mut in_fence = false;
+        for (index, line) in contents.lines().enumerate().skip(body_start - 1) {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence {
+                continue;
+            }
+            let line_number = Some(index + 1);
+            let text = INLINE_CODE.replace_all(line, "");
+            for image in MARKDOWN_IMAGE.captures_iter(&text) {
+                self.check_alt(&image[2], &image[1], line_number, result);
+                self.check_image(&image[2], line_number, bundle_dir, result);
+            }
+            for tag in HTML_IMAGE.find_iter(&text) {
+                let Some(src) = HTML_SRC.captures(tag.as_str()) else {
+                    continue;
+                };
+                let alt = HTML_ALT
+                    .captures(tag.as_str())
+                    .map_or(String::new(), |alt| alt[1].to_string());
+                self.check_alt(&src[1], &alt, line_number, result);
+                self.check_image(&src[1], line_number, bundle_dir, result);
+            }
+        }
+
+        // Banned words anywhere in the file, front matter included
+        if self.config.severity.banned_words != RuleSeverity::Off {
+            let banned: Vec<String> = self
+                .config
+                .banned_words
+                .iter()
+                .map(|word| word.to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect();
+            for (index, line) in contents.lines().enumerate() {
+                let line = line.to_lowercase();
+                for word in banned.iter().filter(|word| contains_word(&line, word)) {
+                    result.add(
+                        self.config.severity.banned_words,
+                        Some(index + 1),
+                        format!("Banned word \"{word}\""),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_alt(&self, src: &str, alt: &str, line: Option<usize>, result: &mut ValidationResult) {
+        if alt.trim().is_empty() {
+            result.add(
+                self.config.severity.alt_text,
+                line,
+                format!("Image \"{src}\" has no alt text"),
+            );
+        }
+    }
+
+    /// Format, file size and dimensions of a local image
+    fn check_image(
+        &self,
+        src: &str,
+        line: Option<usize>,
+        bundle_dir: Option<&Path>,
+        result: &mut ValidationResult,
+    ) {
+        let src = src.trim();
+        if src.is_empty()
+            || src.contains("://")
+            || src.starts_with("//")
+            || src.starts_with("data:")
+        {
+            return;
+        }
+        // Templated sources such as `{{post.image}}-1200.jpg` are only known when rendering
+        if src.contains("{{") {
+            return;
+        }
+        let path_part = src.split(['?', '#']).next().unwrap_or(src);
+        let extension = Path::new(path_part)
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase)
+            .unwrap_or_default();
+        if !self.config.allowed_image_formats.contains(&extension) {
+            let mut allowed: Vec<&str> = self
+                .config
+                .allowed_image_formats
+                .iter()
+                .map(String::as_str)
+                .collect();
+            allowed.sort_unstable();
+            result.add(
+                self.config.severity.image_format,
+                line,
+                format!(
+                    "Image \"{src}\" is not an allowed format ({})",
+                    allowed.join(", ")
+                ),
+            );
+        }
+
+        let file = match bundle_dir {
+            Some(dir) if !path_part.starts_with('/') => dir.join(path_part),
+            _ => self
+                .config
+                .public_dir
+                .join(path_part.trim_start_matches('/')),
+        };
+        let Ok(metadata) = fs::metadata(&file) else {
+            // Missing images are reported by `genwebblog check`
+            return;
+        };
+
+        let size_kb = metadata.len().div_ceil(1024);
+        if size_kb > self.config.max_image_size_kb {
+            result.add(
+                self.config.severity.image_size,
+                line,
+                format!(
+                    "Image \"{src}\" is {size_kb} KB, larger than {} KB",
+                    self.config.max_image_size_kb
+                ),
+            );
+        }
+
+        if self.config.severity.image_dimensions != RuleSeverity::Off && extension != "svg" {
+            if let Ok((width, height)) = image::image_dimensions(&file) {
+                if width > self.config.max_image_width || height > self.config.max_image_height {
+                    result.add(
+                        self.config.severity.image_dimensions,
+                        line,
+                        format!(
+                            "Image \"{src}\" is {width}x{height} pixels, larger than {}x{}",
+                            self.config.max_image_width, self.config.max_image_height
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
     /// Validate date format (basic check)
     pub fn is_valid_date_format(&self, date_str: &str) -> bool {
         let parts: Vec<&str> = date_str.split('-').collect();
@@ -222,11 +562,42 @@ impl FileValidator {
     }
 }
 
+/// `word` in `text`, as a whole word when it starts or ends with an ASCII letter or digit;
+/// scripts written without spaces, such as Thai, match anywhere
+fn contains_word(text: &str, word: &str) -> bool {
+    let is_word_char = |c: char| c.is_ascii_alphanumeric();
+    text.match_indices(word).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + word.len()..].chars().next();
+        let starts_word = word.chars().next().is_some_and(is_word_char);
+        let ends_word = word.chars().next_back().is_some_and(is_word_char);
+        let joined_before = starts_word && before.is_some_and(is_word_char);
+        let joined_after = ends_word && after.is_some_and(is_word_char);
+        !joined_before && !joined_after
+    })
+}
+
+/// A broken rule, with the line it was found on when known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
 /// Validation result for a single file
 #[derive(Debug, Clone)]
 pub struct ValidationResult {
-    pub errors: Vec<String>,
-    pub warnings: Vec<String>,
+    pub errors: Vec<Issue>,
+    pub warnings: Vec<Issue>,
     pub is_valid: bool,
 }
 
@@ -252,12 +623,24 @@ impl ValidationResult {
     }
 
     pub fn add_error(&mut self, error: String) {
-        self.errors.push(error);
-        self.is_valid = false;
+        self.add(RuleSeverity::Error, None, error);
     }
 
     pub fn add_warning(&mut self, warning: String) {
-        self.warnings.push(warning);
+        self.add(RuleSeverity::Warn, None, warning);
+    }
+
+    /// Record a broken rule at its configured severity; `off` drops it
+    pub fn add(&mut self, severity: RuleSeverity, line: Option<usize>, message: String) {
+        let issue = Issue { line, message };
+        match severity {
+            RuleSeverity::Off => {}
+            RuleSeverity::Warn => self.warnings.push(issue),
+            RuleSeverity::Error => {
+                self.errors.push(issue);
+                self.is_valid = false;
+            }
+        }
     }
 
     pub fn has_errors(&self) -> bool {
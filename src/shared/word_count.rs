@@ -0,0 +1,174 @@
+//! Word and character counts for reading time, with dictionary-based Thai segmentation.
+//!
+//! Thai is written without spaces between words, so whitespace tokenisation counts a whole
+//! sentence as one word. Runs of Thai script are split with the embedded word list instead
+//! (fewest unknown characters, then fewest words); other scripts are split on non-letters.
+
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
+use crate::constants::content::THAI_CHARS_PER_UNKNOWN_WORD;
+
+static THAI_WORDS: Lazy<ThaiDictionary> =
+    Lazy::new(|| ThaiDictionary::new(include_str!("data/thai_words.txt")));
+
+struct ThaiDictionary {
+    words: HashSet<&'static str>,
+    /// Longest word, in chars
+    max_len: usize,
+}
+
+impl ThaiDictionary {
+    fn new(list: &'static str) -> Self {
+        let words: HashSet<&str> = list
+            .lines()
+            .map(str::trim)
+            .filter(|w| !w.is_empty() && !w.starts_with('#'))
+            .collect();
+        let max_len = words.iter().map(|w| w.chars().count()).max().unwrap_or(1);
+        Self { words, max_len }
+    }
+}
+
+/// Length metadata of a text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextStats {
+    pub word_count: usize,
+    /// Characters other than whitespace
+    pub char_count: usize,
+}
+
+/// A piece of a Thai run: a dictionary word, or characters no word matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThaiToken<'a> {
+    Word(&'a str),
+    Unknown(&'a str),
+}
+
+/// Count words and characters of plain text
+#[must_use]
+pub fn text_stats(text: &str) -> TextStats {
+    let mut stats = TextStats::default();
+    let mut word_start: Option<usize> = None;
+    let mut thai_start: Option<usize> = None;
+
+    for (i, c) in text.char_indices() {
+        if !c.is_whitespace() {
+            stats.char_count += 1;
+        }
+
+        if is_thai_letter(c) {
+            if let Some(start) = word_start.take() {
+                stats.word_count += usize::from(start < i);
+            }
+            thai_start.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = thai_start.take() {
+            stats.word_count += count_thai_words(&text[start..i]);
+        }
+
+        if is_ideograph(c) {
+            word_start = None;
+            stats.word_count += 1;
+        } else if c.is_alphanumeric() || (word_start.is_some() && matches!(c, '\'' | '’' | '-')) {
+            word_start.get_or_insert(i);
+        } else if word_start.take().is_some() {
+            stats.word_count += 1;
+        }
+    }
+
+    if let Some(start) = thai_start {
+        stats.word_count += count_thai_words(&text[start..]);
+    }
+    if word_start.is_some() {
+        stats.word_count += 1;
+    }
+    stats
+}
+
+fn count_thai_words(run: &str) -> usize {
+    segment_thai(run)
+        .iter()
+        .map(|token| match token {
+            ThaiToken::Word(_) => 1,
+            ThaiToken::Unknown(chars) => {
+                chars.chars().count().div_ceil(THAI_CHARS_PER_UNKNOWN_WORD)
+            }
+        })
+        .sum()
+}
+
+/// Split a run of Thai text into dictionary words, keeping unmatched characters together
+#[must_use]
+pub fn segment_thai(run: &str) -> Vec<ThaiToken<'_>> {
+    let dictionary = &*THAI_WORDS;
+    let offsets: Vec<usize> = run
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(run.len()))
+        .collect();
+    let n = offsets.len() - 1;
+
+    // best[i][state]: (unknown chars, tokens) for the first i chars, state 1 = ends in unknown text
+    let mut best = vec![[(usize::MAX, usize::MAX); 2]; n + 1];
+    let mut back = vec![[(0usize, 0usize); 2]; n + 1];
+    best[0][0] = (0, 0);
+
+    for i in 0..n {
+        for state in 0..2 {
+            let (unknown, tokens) = best[i][state];
+            if unknown == usize::MAX {
+                continue;
+            }
+            for len in 1..=dictionary.max_len.min(n - i) {
+                if dictionary
+                    .words
+                    .contains(&run[offsets[i]..offsets[i + len]])
+                {
+                    let cost = (unknown, tokens + 1);
+                    if cost < best[i + len][0] {
+                        best[i + len][0] = cost;
+                        back[i + len][0] = (i, state);
+                    }
+                }
+            }
+            let cost = (unknown + 1, tokens + usize::from(state == 0));
+            if cost < best[i + 1][1] {
+                best[i + 1][1] = cost;
+                back[i + 1][1] = (i, state);
+            }
+        }
+    }
+
+    let mut tokens = Vec::new();
+    let mut end = n;
+    let mut state = usize::from(best[n][1] < best[n][0]);
+    let mut unknown_end = None;
+    while end > 0 {
+        let (start, prev_state) = back[end][state];
+        if state == 0 {
+            tokens.push(ThaiToken::Word(&run[offsets[start]..offsets[end]]));
+        } else {
+            let stop = *unknown_end.get_or_insert(end);
+            if prev_state == 0 {
+                tokens.push(ThaiToken::Unknown(&run[offsets[start]..offsets[stop]]));
+                unknown_end = None;
+            }
+        }
+        end = start;
+        state = prev_state;
+    }
+    tokens.reverse();
+    tokens
+}
+
+/// Thai consonants, vowels and tone marks; digits, `ฯ` and `ๆ` are left out
+fn is_thai_letter(c: char) -> bool {
+    matches!(c, '\u{0E01}'..='\u{0E2E}' | '\u{0E30}'..='\u{0E3A}' | '\u{0E40}'..='\u{0E45}' | '\u{0E47}'..='\u{0E4E}')
+}
+
+/// Chinese, Japanese and Korean ideographs count as one word each
+fn is_ideograph(c: char) -> bool {
+    matches!(c, '\u{3040}'..='\u{30FF}' | '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{AC00}'..='\u{D7AF}')
+}
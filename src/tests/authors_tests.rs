@@ -0,0 +1,133 @@
+use crate::app::{AppConfig, AuthorInfo};
+use crate::blog::Article;
+use crate::generator::authors::AuthorRegistry;
+use crate::posts::Post;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+fn make_app() -> AppConfig {
+    let mut cfg = AppConfig::default();
+    cfg.languages.installed_languages = vec!["th".to_string(), "en".to_string()];
+    cfg.languages.default_language = "th".to_string();
+    cfg.app_info.app_domain = "https://example.com".to_string();
+    cfg
+}
+
+fn make_registry() -> AuthorRegistry {
+    let mut authors = BTreeMap::new();
+    authors.insert(
+        "boy".to_string(),
+        AuthorInfo {
+            name: "บอย".to_string(),
+            names: Some(BTreeMap::from([("en".to_string(), "Boy".to_string())])),
+            email: Some("boy@example.com".to_string()),
+            social: Some(BTreeMap::from([(
+                "github".to_string(),
+                "https://github.com/boy".to_string(),
+            )])),
+            ..Default::default()
+        },
+    );
+    authors.insert(
+        "jane".to_string(),
+        AuthorInfo {
+            name: "Jane Doe".to_string(),
+            ..Default::default()
+        },
+    );
+    AuthorRegistry::new(authors)
+}
+
+fn make_post(slug: &str, author: &str, authors: &[&str]) -> Post {
+    Post {
+        title: slug.to_string(),
+        url: format!("/blog/{slug}.html"),
+        author: author.to_string(),
+        authors: authors.iter().map(ToString::to_string).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn resolves_author_ids_and_names() {
+    let app = make_app();
+    let registry = make_registry();
+
+    let mut post = make_post("a", "boy", &[]);
+    registry.resolve_post(&mut post, &app, "th");
+    assert_eq!(post.authors, vec!["boy"]);
+    assert_eq!(post.author, "บอย");
+    assert_eq!(post.author_url, "https://example.com/authors/boy/");
+    assert_eq!(post.author_email, "boy@example.com");
+
+    let mut by_name = make_post("b", "jane doe", &[]);
+    registry.resolve_post(&mut by_name, &app, "th");
+    assert_eq!(by_name.authors, vec!["jane"]);
+
+    let mut co_written = make_post("c", "", &["jane", "boy", "nobody"]);
+    registry.resolve_post(&mut co_written, &app, "th");
+    assert_eq!(co_written.authors, vec!["jane", "boy"]);
+    assert_eq!(co_written.author, "Jane Doe, บอย");
+
+    let mut guest = make_post("d", "Guest Writer", &[]);
+    registry.resolve_post(&mut guest, &app, "th");
+    assert!(guest.authors.is_empty());
+    assert_eq!(guest.author, "Guest Writer");
+
+    // A translated post shows the author's name and profile in its own language
+    let mut translated = make_post("e", "boy", &[]);
+    registry.resolve_post(&mut translated, &app, "en");
+    assert_eq!(translated.author, "Boy");
+    assert_eq!(translated.author_url, "https://example.com/en/authors/boy/");
+}
+
+#[test]
+fn author_pages_list_posts_per_language() {
+    let app = make_app();
+    let registry = make_registry();
+    let mut posts = vec![
+        make_post("a", "boy", &[]),
+        make_post("b", "", &["boy", "jane"]),
+    ];
+    for post in &mut posts {
+        registry.resolve_post(post, &app, "en");
+    }
+    let article = Article {
+        layout: "articles".to_string(),
+        title: "Blog".to_string(),
+        description: String::new(),
+        image: String::new(),
+        prefix: PathBuf::from("blog"),
+        app_domain: String::new(),
+        posts,
+        link_text: "Blog".to_string(),
+        keywords: Vec::new(),
+        author: String::new(),
+        author_url: String::new(),
+        author_email: String::new(),
+        lang: "en".to_string(),
+        draft: false,
+        date_modified: String::new(),
+        date_published: String::new(),
+        category: String::new(),
+        paginate: None,
+        scheduled: Vec::new(),
+        expired: Vec::new(),
+        params: Default::default(),
+    };
+
+    let authors = registry.build_all(&app, &[&article], "en");
+    assert_eq!(authors.len(), 2);
+    assert_eq!(authors[0].id, "boy");
+    assert_eq!(authors[0].name, "Boy");
+    assert_eq!(authors[0].url, "/en/authors/boy/");
+    assert_eq!(authors[0].same_as, vec!["https://github.com/boy"]);
+    assert_eq!(authors[0].posts.len(), 2);
+    assert_eq!(authors[1].posts.len(), 1);
+
+    let for_post = registry.for_post(&article.posts[1], &app, "th");
+    assert_eq!(
+        for_post.iter().map(|a| a.name.as_str()).collect::<Vec<_>>(),
+        vec!["บอย", "Jane Doe"]
+    );
+}
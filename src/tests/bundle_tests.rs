@@ -0,0 +1,77 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use crate::app::AppConfig;
+use crate::constants::content::READING_TIME_WPM;
+use crate::posts::Post;
+use crate::shared::bundle::Bundle;
+use crate::shared::markdown_to_html::options::options;
+
+fn write_bundle(base: &Path) -> PathBuf {
+    let dir = base.join("2025-07-03-eeat-guide");
+    fs::create_dir_all(dir.join("files")).unwrap();
+    fs::write(
+        dir.join("index.md"),
+        "---\ntitle: \"EEAT\"\nimage: cover.svg\n---\n\n\
+         ![Chart](chart.png)\n\n[Checklist](./files/checklist.pdf) \
+         [Missing](gone.png) [Site](https://example.com/chart.png) [Top](#top)\n",
+    )
+    .unwrap();
+    fs::write(dir.join("cover.svg"), "<svg/>").unwrap();
+    fs::write(dir.join("chart.png"), b"png").unwrap();
+    fs::write(dir.join("files/checklist.pdf"), b"pdf").unwrap();
+    dir
+}
+
+#[test]
+fn bundle_post_takes_date_and_slug_from_its_directory() {
+    let temp = TempDir::new().unwrap();
+    let dir = write_bundle(temp.path());
+    let post = Post::open(
+        &dir.join("index.md"),
+        &AppConfig::default(),
+        Path::new("seo"),
+        "/{section}/{slug}.html",
+        "/en",
+        &options(&AppConfig::default(), None),
+        READING_TIME_WPM,
+    )
+    .unwrap();
+
+    assert_eq!((post.year, post.month, post.day), (2025, 7, 3));
+    assert_eq!(post.url, "/seo/eeat-guide.html");
+    assert_eq!(post.image, "/en/seo/eeat-guide/cover.svg");
+    assert!(post
+        .contents
+        .contains("src=\"/en/seo/eeat-guide/chart.png\""));
+    assert!(post
+        .contents
+        .contains("href=\"/en/seo/eeat-guide/files/checklist.pdf\""));
+    // Only files that exist in the bundle are rewritten
+    assert!(post.contents.contains("href=\"gone.png\""));
+    assert!(post
+        .contents
+        .contains("href=\"https://example.com/chart.png\""));
+    assert!(post.contents.contains("href=\"#top\""));
+
+    let bundle = post.bundle.unwrap();
+    assert_eq!(
+        bundle.assets().unwrap(),
+        [
+            PathBuf::from("chart.png"),
+            PathBuf::from("cover.svg"),
+            Path::new("files").join("checklist.pdf"),
+        ]
+    );
+}
+
+#[test]
+fn pretty_urls_serve_bundle_files_beside_the_page() {
+    let bundle = Bundle::new(Path::new("contents/seo/x"), "", "/seo/2025/eeat-guide/");
+    assert_eq!(bundle.url, "/seo/2025/eeat-guide/");
+    assert_eq!(bundle.resolve("index.md"), None);
+    assert_eq!(bundle.resolve("../other/chart.png"), None);
+    assert_eq!(bundle.resolve("mailto:me@example.com"), None);
+}
@@ -0,0 +1,145 @@
+use std::fs;
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use crate::app::{AppConfig, FrontmatterSchema};
+use crate::generator::check::{check_contents, CheckReport, Severity};
+use crate::validation::{FileValidator, ValidationConfig};
+
+fn check(dir: &Path, app: &AppConfig) -> CheckReport {
+    check_contents(app, dir, &FileValidator::new(ValidationConfig::default()))
+}
+
+/// `(file name, line, severity)` of every problem
+fn located(report: &CheckReport) -> Vec<(String, Option<usize>, Severity)> {
+    report
+        .problems
+        .iter()
+        .map(|p| {
+            let name = p.file.file_name().unwrap().to_string_lossy().to_string();
+            (name, p.line, p.severity)
+        })
+        .collect()
+}
+
+#[test]
+fn valid_content_has_no_problems() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    fs::create_dir_all(dir.join("seo")).unwrap();
+    fs::write(dir.join("seo/index.yml"), "title: SEO\n").unwrap();
+    fs::write(
+        dir.join("seo/2025-07-03-guide.md"),
+        "---\ntitle: Guide\ndate: 2025-07-03\n---\n\nBody\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("about.yml"),
+        "title: About\ndescription: Who we are\n",
+    )
+    .unwrap();
+
+    let report = check(dir, &AppConfig::default());
+
+    assert!(report.problems.is_empty(), "{:?}", report.problems);
+    assert_eq!(report.files, 3);
+}
+
+#[test]
+fn every_broken_file_is_reported_with_its_line() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    fs::create_dir_all(dir.join("seo")).unwrap();
+    fs::write(dir.join("seo/index.yml"), "title: SEO\n").unwrap();
+    fs::write(
+        dir.join("seo/2025-07-01-bad-date.md"),
+        "---\ntitle: Bad date\ndate: 2025-13-45\n---\n\nBody\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("seo/2025-07-02-broken.md"),
+        "---\ntitle: \"unterminated\n---\n\nBody\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("seo/2025-07-03-no-image.md"),
+        "---\ntitle: No image\ndate: 2025-07-03\nimage: /images/none.webp\n---\n\nBody\n",
+    )
+    .unwrap();
+
+    let report = check(dir, &AppConfig::default());
+
+    assert_eq!(
+        located(&report),
+        vec![
+            (
+                "2025-07-01-bad-date.md".to_string(),
+                Some(3),
+                Severity::Fatal
+            ),
+            ("2025-07-02-broken.md".to_string(), Some(3), Severity::Fatal),
+            (
+                "2025-07-03-no-image.md".to_string(),
+                Some(4),
+                Severity::Error
+            ),
+        ]
+    );
+    assert!(report.has_fatal());
+    assert_eq!(report.errors(), 3);
+    assert!(report.problems[2]
+        .to_string()
+        .ends_with(":4: error: image \"/images/none.webp\" not found at public/images/none.webp"));
+}
+
+#[test]
+fn schema_requires_fields_and_limits_values() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    fs::create_dir_all(dir.join("seo")).unwrap();
+    fs::write(dir.join("seo/index.yml"), "title: SEO\n").unwrap();
+    fs::write(
+        dir.join("seo/2025-07-03-guide.md"),
+        "---\ntitle: A title that is longer than twenty characters\ndate: 2025-07-03\n\
+         category: recipes\n---\n\nBody\n",
+    )
+    .unwrap();
+    let app = AppConfig {
+        frontmatter: Some(FrontmatterSchema {
+            required: Some(vec!["title".to_string(), "description".to_string()]),
+            values: Some([("category".to_string(), vec!["seo".to_string()])].into()),
+            title_max_length: Some(20),
+            description_max_length: None,
+        }),
+        ..Default::default()
+    };
+
+    let report = check(dir, &app);
+    let messages: Vec<String> = report.problems.iter().map(|p| p.message.clone()).collect();
+
+    assert_eq!(
+        messages,
+        vec![
+            "`description` is required".to_string(),
+            "`title` is 45 characters, longer than 20; it may be truncated in search results"
+                .to_string(),
+            "`category` \"recipes\" is not one of: seo".to_string(),
+        ]
+    );
+    assert_eq!(report.errors(), 2);
+    assert_eq!(report.warnings(), 1);
+    assert!(!report.has_fatal());
+}
+
+#[test]
+fn pages_need_a_title_and_description() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("about.yml"), "title: About\n").unwrap();
+
+    let report = check(temp.path(), &AppConfig::default());
+
+    assert_eq!(report.problems.len(), 1);
+    assert_eq!(report.problems[0].message, "`description` is required");
+    assert_eq!(report.problems[0].severity, Severity::Error);
+}
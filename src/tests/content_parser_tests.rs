@@ -30,6 +30,10 @@ fn make_article(prefix: &str, lang: &str) -> Article {
         date_modified: "".to_string(),
         date_published: "".to_string(),
         category: "".to_string(),
+        paginate: None,
+        scheduled: Vec::new(),
+        expired: Vec::new(),
+        params: Default::default(),
     }
 }
 
@@ -38,12 +42,16 @@ fn parse_articles_includes_root_and_subdirs() {
     let app = make_minimal_app();
     let parser = ContentParser::new(&app);
 
-    let a1 = make_article("", ""); 
+    let a1 = make_article("", "");
     let a2 = make_article("web-optimization", "en");
 
     let articles = vec![a1, a2];
     let listing = parser.parse_articles_for_listing(&articles, "en");
 
-    assert!(listing.iter().any(|v| v.get("url").and_then(|u| u.as_str()) == Some("/")));
-    assert!(listing.iter().any(|v| v.get("url").and_then(|u| u.as_str()) == Some("/web-optimization")));
+    assert!(listing
+        .iter()
+        .any(|v| v.get("url").and_then(|u| u.as_str()) == Some("/")));
+    assert!(listing
+        .iter()
+        .any(|v| v.get("url").and_then(|u| u.as_str()) == Some("/web-optimization")));
 }
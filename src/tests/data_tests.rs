@@ -0,0 +1,97 @@
+use std::fs;
+
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::generator::data::{parse_csv, SiteData};
+
+fn languages() -> Vec<String> {
+    vec!["th".to_string(), "en".to_string()]
+}
+
+#[test]
+fn data_files_become_a_nested_tree() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    fs::create_dir_all(dir.join("team")).unwrap();
+    fs::write(dir.join("faq.yml"), "- q: Free?\n  a: Yes\n").unwrap();
+    fs::write(dir.join("pricing.toml"), "[pro]\nprice = 9\n").unwrap();
+    fs::write(dir.join("site.json"), r#"{"tagline": "Fast"}"#).unwrap();
+    fs::write(dir.join("team/members.csv"), "name,role\nAnn,Editor\n").unwrap();
+    fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+    let data = SiteData::load_from(dir, &languages()).unwrap();
+
+    assert_eq!(
+        data.for_language("th"),
+        json!({
+            "faq": [{"q": "Free?", "a": "Yes"}],
+            "pricing": {"pro": {"price": 9}},
+            "site": {"tagline": "Fast"},
+            "team": {"members": [{"name": "Ann", "role": "Editor"}]},
+        })
+    );
+}
+
+#[test]
+fn language_folders_override_shared_data() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path();
+    fs::create_dir_all(dir.join("en")).unwrap();
+    fs::write(dir.join("site.yml"), "tagline: เร็ว\nyear: 2025\n").unwrap();
+    fs::write(dir.join("en/site.yml"), "tagline: Fast\n").unwrap();
+
+    let data = SiteData::load_from(dir, &languages()).unwrap();
+
+    assert_eq!(
+        data.for_language("th"),
+        json!({"site": {"tagline": "เร็ว", "year": 2025}})
+    );
+    assert_eq!(
+        data.for_language("en"),
+        json!({"site": {"tagline": "Fast", "year": 2025}})
+    );
+}
+
+#[test]
+fn missing_directory_is_empty() {
+    let temp = TempDir::new().unwrap();
+    let data = SiteData::load_from(&temp.path().join("data"), &languages()).unwrap();
+    assert_eq!(data.for_language("th"), json!({}));
+}
+
+#[test]
+fn invalid_file_is_reported_by_name() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("broken.json"), "{").unwrap();
+    let error = SiteData::load_from(temp.path(), &languages()).unwrap_err();
+    assert!(format!("{error:#}").contains("broken.json"));
+}
+
+#[test]
+fn csv_handles_quotes_and_short_rows() {
+    let csv = "name,quote,note\r\n\"Doe, Jane\",\"She said \"\"hi\"\"\nthen left\"\nBob\n";
+    assert_eq!(
+        parse_csv(csv).unwrap(),
+        json!([
+            {"name": "Doe, Jane", "quote": "She said \"hi\"\nthen left", "note": ""},
+            {"name": "Bob", "quote": "", "note": ""},
+        ])
+    );
+    assert!(parse_csv("a\n\"open").is_err());
+    assert!(parse_csv("a\n1,2\n").is_err());
+}
+
+#[test]
+fn csv_errors_name_the_line_the_record_starts_on() {
+    // A blank line and a quoted field spanning two lines come before the bad record
+    let csv = "name,note\n\nAnn,\"first\nsecond\"\nBob,x,extra\n";
+    assert_eq!(
+        parse_csv(csv).unwrap_err(),
+        "line 5 has 3 fields, the header has 2"
+    );
+    assert_eq!(
+        parse_csv("a\n1\n\"open\n").unwrap_err(),
+        "unterminated quoted field on line 3"
+    );
+}
@@ -0,0 +1,167 @@
+use crate::app::{AppConfig, Taxonomies};
+use crate::blog::Article;
+use crate::generator::feed::{FeedFormat, FeedGenerator};
+use crate::posts::Post;
+use std::path::PathBuf;
+
+fn make_app() -> AppConfig {
+    let mut cfg = AppConfig::default();
+    cfg.app_info.app_name = "Blog".to_string();
+    cfg.app_info.app_domain = "https://example.com".to_string();
+    cfg.languages.installed_languages = vec!["th".to_string(), "en".to_string()];
+    cfg.languages.default_language = "th".to_string();
+    cfg
+}
+
+fn make_post(title: &str, url: &str, day: u32, tags: &[&str]) -> Post {
+    Post {
+        title: title.to_string(),
+        url: url.to_string(),
+        year: 2025,
+        month: 7,
+        day,
+        published: format!("2025-07-{day:02}T00:00:00+00:00"),
+        updated: format!("2025-07-{day:02}T00:00:00+00:00"),
+        description: format!("About {title}"),
+        contents: "<p>Body ]]> end</p>".to_string(),
+        tags: Some(tags.iter().map(ToString::to_string).collect()),
+        taxonomies: [(
+            "tags".to_string(),
+            tags.iter().map(ToString::to_string).collect(),
+        )]
+        .into_iter()
+        .collect(),
+        ..Default::default()
+    }
+}
+
+fn make_article(prefix: &str, posts: Vec<Post>) -> Article {
+    Article {
+        layout: "articles".to_string(),
+        title: "SEO".to_string(),
+        description: "SEO articles".to_string(),
+        image: String::new(),
+        prefix: PathBuf::from(prefix),
+        app_domain: "https://example.com".to_string(),
+        posts,
+        link_text: "SEO".to_string(),
+        keywords: Vec::new(),
+        author: String::new(),
+        author_url: String::new(),
+        author_email: String::new(),
+        lang: "en".to_string(),
+        draft: false,
+        date_modified: String::new(),
+        date_published: String::new(),
+        category: String::new(),
+        paginate: None,
+        scheduled: Vec::new(),
+        expired: Vec::new(),
+        params: Default::default(),
+    }
+}
+
+#[test]
+fn feeds_cover_language_section_and_tags() {
+    let app = make_app();
+    let article = make_article(
+        "seo",
+        vec![
+            make_post("First", "/seo/first.html", 1, &["SEO Tips"]),
+            make_post("Second", "/seo/second.html", 2, &[]),
+        ],
+    );
+    let articles = vec![&article];
+
+    let feeds = FeedGenerator::new(&app).feeds_for_language(&articles, "en");
+    let paths: Vec<&str> = feeds.iter().map(|f| f.base_path.as_str()).collect();
+    assert_eq!(paths, vec!["/en/", "/en/seo/", "/en/tags/seo-tips/"]);
+    assert_eq!(feeds[0].posts[0].title, "Second", "newest post first");
+}
+
+#[test]
+fn tag_feeds_follow_the_tags_taxonomy() {
+    let mut app = make_app();
+    let article = make_article(
+        "seo",
+        vec![make_post("First", "/seo/first.html", 1, &["SEO Tips"])],
+    );
+    let articles = vec![&article];
+    let paths = |app: &AppConfig| -> Vec<String> {
+        FeedGenerator::new(app)
+            .feeds_for_language(&articles, "en")
+            .into_iter()
+            .map(|f| f.base_path)
+            .collect()
+    };
+
+    // No term pages are written for a taxonomy that is not configured
+    app.taxonomies = Some(Taxonomies {
+        enabled: None,
+        names: Some(vec!["categories".to_string()]),
+    });
+    assert_eq!(paths(&app), vec!["/en/", "/en/seo/"]);
+
+    app.taxonomies = Some(Taxonomies {
+        enabled: Some(false),
+        names: None,
+    });
+    assert_eq!(paths(&app), vec!["/en/", "/en/seo/"]);
+}
+
+#[test]
+fn atom_entry_without_a_date_takes_the_feed_update_time() {
+    let app = make_app();
+    let mut undated = make_post("Undated", "/seo/undated.html", 1, &[]);
+    undated.published = String::new();
+    undated.updated = String::new();
+    let article = make_article(
+        "seo",
+        vec![undated, make_post("Second", "/seo/second.html", 2, &[])],
+    );
+    let articles = vec![&article];
+    let generator = FeedGenerator::new(&app);
+    let feeds = generator.feeds_for_language(&articles, "en");
+
+    let atom = generator.render_atom(&feeds[0]);
+    let entry = &atom[atom.find("<title>Undated</title>").unwrap()..];
+    let entry = &entry[..entry.find("</entry>").unwrap()];
+    assert!(entry.contains("<updated>2025-07-02T00:00:00+00:00</updated>"));
+}
+
+#[test]
+fn rss_and_atom_use_absolute_language_urls() {
+    let app = make_app();
+    let article = make_article("seo", vec![make_post("First", "/seo/first.html", 1, &[])]);
+    let articles = vec![&article];
+    let generator = FeedGenerator::new(&app);
+    let feeds = generator.feeds_for_language(&articles, "en");
+
+    let rss = generator.render_rss(&feeds[0]);
+    assert!(rss.contains("<link>https://example.com/en/seo/first.html</link>"));
+    assert!(rss.contains("<pubDate>Tue, 1 Jul 2025 00:00:00 +0000</pubDate>"));
+    assert!(rss.contains("]]]]><![CDATA[>"), "CDATA terminator is split");
+
+    let atom = generator.render_atom(&feeds[0]);
+    assert!(atom.contains("<updated>2025-07-01T00:00:00+00:00</updated>"));
+
+    let json = generator.render_json(&feeds[0]);
+    assert_eq!(json["version"], "https://jsonfeed.org/version/1.1");
+    assert_eq!(json["feed_url"], "https://example.com/en/feed.json");
+}
+
+#[test]
+fn feed_links_follow_configured_formats() {
+    let mut app = make_app();
+    app.feeds = Some(crate::app::Feeds {
+        formats: Some(vec!["atom".to_string()]),
+        ..Default::default()
+    });
+    let generator = FeedGenerator::new(&app);
+    assert_eq!(generator.formats(), vec![FeedFormat::Atom]);
+
+    let links = generator.links("th", None);
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].href, "https://example.com/atom.xml");
+    assert_eq!(links[0].mime_type, "application/atom+xml");
+}
@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::shared::error::GenWebBlogError;
+use crate::shared::frontmatter::{parse, split, Format};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Header {
+    title: String,
+    #[serde(default)]
+    date: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+fn path() -> &'static Path {
+    Path::new("contents/2025-01-01-post.md")
+}
+
+fn error_line(err: GenWebBlogError) -> (usize, usize) {
+    match err {
+        GenWebBlogError::Frontmatter { line, column, .. } => (line, column),
+        other => panic!("expected a front matter error, got {other}"),
+    }
+}
+
+#[test]
+fn yaml_delimiters_must_be_whole_lines() {
+    let content = "---\ntitle: \"Before --- after\"\ntags: [a]\n---\n\nBody\n";
+    let (header, body) = parse::<Header>(path(), content).unwrap();
+    assert_eq!(header.title, "Before --- after");
+    assert_eq!(header.tags, ["a"]);
+    assert_eq!(body, "\nBody\n");
+}
+
+#[test]
+fn toml_and_json_front_matter() {
+    let toml = "+++\ntitle = \"TOML\"\ndate = 2025-07-03T09:00:00+07:00\n+++\nBody\n";
+    let (header, body) = parse::<Header>(path(), toml).unwrap();
+    assert_eq!(header.date.as_deref(), Some("2025-07-03T09:00:00+07:00"));
+    assert_eq!(body, "Body\n");
+
+    let json = "{\n  \"title\": \"JSON {braces}\",\n  \"tags\": [\"x\"]\n}\nBody\n";
+    let frontmatter = split(path(), json).unwrap().unwrap();
+    assert_eq!(frontmatter.format, Format::Json);
+    assert_eq!(frontmatter.body, "Body\n");
+    let header: Header = frontmatter.deserialize(path()).unwrap();
+    assert_eq!(header.title, "JSON {braces}");
+
+    assert!(split(path(), "# No front matter\n").unwrap().is_none());
+}
+
+#[test]
+fn errors_point_at_the_line_in_the_file() {
+    // The bad value is on line 3 of the file, line 2 of the YAML block
+    let yaml = "---\ntitle: ok\ndate: [2025, 1]\n---\n";
+    let (line, _) = error_line(parse::<Header>(path(), yaml).unwrap_err());
+    assert_eq!(line, 3);
+
+    let toml = "+++\ntitle = \"ok\"\n\ntags = [\"a\" \"b\"]\n+++\n";
+    assert_eq!(error_line(parse::<Header>(path(), toml).unwrap_err()).0, 4);
+
+    let json = "{\n  \"title\": \"ok\",\n  \"tags\": [1,]\n}\n";
+    assert_eq!(error_line(parse::<Header>(path(), json).unwrap_err()).0, 3);
+
+    let message = parse::<Header>(path(), "---\ntitle: a\n")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        message.contains("contents/2025-01-01-post.md:1:1"),
+        "{message}"
+    );
+    assert!(message.contains("never closed"), "{message}");
+}
+
+#[test]
+fn any_format_deserializes_into_a_map() {
+    let fields: BTreeMap<String, serde_yaml::Value> =
+        split(path(), "+++\ndate = 2025-01-01\n+++\n")
+            .unwrap()
+            .unwrap()
+            .deserialize(path())
+            .unwrap();
+    assert_eq!(fields["date"].as_str(), Some("2025-01-01"));
+}
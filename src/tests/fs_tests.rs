@@ -1,4 +1,4 @@
-use crate::shared::fs::{read_file_to_string, write_file, copy_dir_contents};
+use crate::shared::fs::{copy_dir_contents, read_file_to_string, write_file};
 use tempfile::tempdir;
 
 #[test]
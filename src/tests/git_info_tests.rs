@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use git2::{Repository, Signature, Time};
+use tempfile::TempDir;
+
+use crate::generator::git_info::{edit_url, GitHistory};
+
+fn commit(repo: &Repository, author: &str, seconds: i64, files: &[(&str, &str)]) {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    let mut index = repo.index().unwrap();
+    for (path, contents) in files {
+        let full = workdir.join(path);
+        fs::create_dir_all(full.parent().unwrap()).unwrap();
+        fs::write(&full, contents).unwrap();
+        index.add_path(Path::new(path)).unwrap();
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::new(author, "author@example.com", &Time::new(seconds, 420)).unwrap();
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    let parents: Vec<_> = parent.iter().collect();
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        "update",
+        &tree,
+        &parents,
+    )
+    .unwrap();
+}
+
+fn paths(list: &[&str]) -> HashSet<String> {
+    list.iter().map(|p| (*p).to_string()).collect()
+}
+
+#[test]
+fn last_commit_of_each_file_is_found() {
+    let temp = TempDir::new().unwrap();
+    let repo = Repository::init(temp.path()).unwrap();
+    commit(
+        &repo,
+        "Ann",
+        1_700_000_000,
+        &[("contents/a.md", "a"), ("contents/b.md", "b")],
+    );
+    commit(&repo, "Bo", 1_700_086_400, &[("contents/b.md", "b2")]);
+    fs::write(temp.path().join("contents/new.md"), "draft").unwrap();
+
+    let history = GitHistory::discover(temp.path()).unwrap();
+    let commits = history
+        .last_commits(&paths(&[
+            "contents/a.md",
+            "contents/b.md",
+            "contents/new.md",
+        ]))
+        .unwrap();
+
+    assert_eq!(commits["contents/a.md"].author, "Ann");
+    assert_eq!(commits["contents/b.md"].author, "Bo");
+    assert_eq!(commits["contents/b.md"].date, "2023-11-16T05:13:20+07:00");
+    assert_eq!(commits["contents/b.md"].day, "2023-11-16");
+    assert_eq!(commits["contents/b.md"].short_hash.len(), 7);
+    assert!(!commits.contains_key("contents/new.md"));
+    assert_eq!(
+        history
+            .repo_path(&temp.path().join("contents/a.md"))
+            .as_deref(),
+        Some("contents/a.md")
+    );
+}
+
+#[test]
+fn cache_is_reused_and_extended_by_new_commits() {
+    let temp = TempDir::new().unwrap();
+    let repo = Repository::init(temp.path()).unwrap();
+    commit(&repo, "Ann", 1_700_000_000, &[("a.md", "a"), ("b.md", "b")]);
+
+    let history = GitHistory::discover(temp.path()).unwrap();
+    let wanted = paths(&["a.md", "b.md"]);
+    let first = history.last_commits(&wanted).unwrap();
+    assert!(repo.path().join("genwebblog-last-commits.json").exists());
+
+    commit(&repo, "Bo", 1_700_086_400, &[("b.md", "b2")]);
+    let second = history.last_commits(&wanted).unwrap();
+    assert_eq!(second["a.md"], first["a.md"]);
+    assert_eq!(second["b.md"].author, "Bo");
+}
+
+#[test]
+fn edit_url_fills_in_the_repository_path() {
+    assert_eq!(
+        edit_url(
+            "https://github.com/user/blog/edit/main/{path}",
+            "contents/seo/post.md"
+        ),
+        "https://github.com/user/blog/edit/main/contents/seo/post.md"
+    );
+}
@@ -32,7 +32,6 @@ fn test_build_command_generates_files() {
         temp_path.join("build/index.html").exists(),
         "index.html was not generated"
     );
-
 }
 
 #[test]
@@ -252,9 +251,7 @@ fn test_help_command() {
     assert!(output_str.contains("DEPLOYMENT"), "Help sections missing");
 }
 
-
 fn copy_test_content(temp_path: &Path) {
-
     fs::create_dir_all(temp_path.join("contents")).expect("Failed to create contents directory");
     fs::create_dir_all(temp_path.join("source/templates"))
         .expect("Failed to create templates directory");
@@ -295,7 +292,6 @@ fn copy_dir_all(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> std::io::Result
 }
 
 fn create_minimal_test_content(temp_path: &Path) {
-
     let index_yml = r#"title: "Test Blog"
 description: "A test blog for integration testing"
 image: ""
@@ -0,0 +1,60 @@
+use crate::app::{AppConfig, Markdown};
+use crate::shared::markdown_to_html::comrak_custom::markdown_to_html;
+use crate::shared::markdown_to_html::options::options;
+
+#[test]
+fn defaults_keep_the_original_extensions() {
+    let options = options(&AppConfig::default(), None);
+
+    assert!(options.render.unsafe_);
+    assert!(options.extension.strikethrough);
+    assert!(options.extension.table);
+    assert!(options.extension.footnotes);
+    assert_eq!(options.extension.header_ids.as_deref(), Some(""));
+    assert!(!options.extension.math_dollars);
+    assert!(!options.extension.alerts);
+    assert!(!options.parse.smart);
+    assert!(!options.render.hardbreaks);
+}
+
+#[test]
+fn section_settings_override_the_site_settings() {
+    let app = AppConfig {
+        markdown: Some(Markdown {
+            math: Some(true),
+            tables: Some(false),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let section: Markdown = serde_yaml::from_str("math: false\nalerts: true\n").unwrap();
+
+    let options = options(&app, Some(&section));
+
+    assert!(!options.extension.math_dollars);
+    assert!(options.extension.alerts);
+    // Left to the site setting
+    assert!(!options.extension.table);
+}
+
+#[test]
+fn enabled_extensions_render() {
+    let app = AppConfig {
+        markdown: Some(Markdown {
+            tasklists: Some(true),
+            spoilers: Some(true),
+            hard_breaks: Some(true),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let html = markdown_to_html(
+        "- [x] done\n\n||hidden||\nnext line\n",
+        &options(&app, None),
+    );
+
+    assert!(html.contains("<input type=\"checkbox\" checked=\"\" disabled=\"\" />"));
+    assert!(html.contains("<span class=\"spoiler\">hidden</span>"));
+    assert!(html.contains("<br />"));
+}
@@ -4,13 +4,82 @@ mod content_parser_tests;
 #[cfg(test)]
 mod fs_tests;
 
-#[cfg(test)]
-mod validation_tests;
 #[cfg(test)]
 pub mod integration_tests;
+#[cfg(test)]
+mod validation_tests;
 
 #[cfg(test)]
 pub mod performance_tests;
 
 #[cfg(test)]
 pub mod security_tests;
+
+#[cfg(test)]
+mod sitemap_tests;
+
+#[cfg(test)]
+mod feed_tests;
+
+#[cfg(test)]
+mod taxonomy_tests;
+
+#[cfg(test)]
+mod paginator_tests;
+
+#[cfg(test)]
+mod schedule_tests;
+
+#[cfg(test)]
+mod series_tests;
+
+#[cfg(test)]
+mod related_tests;
+
+#[cfg(test)]
+mod authors_tests;
+
+#[cfg(test)]
+mod permalink_tests;
+
+#[cfg(test)]
+mod redirects_tests;
+
+#[cfg(test)]
+mod word_count_tests;
+
+#[cfg(test)]
+mod toc_tests;
+
+#[cfg(test)]
+mod summary_tests;
+
+#[cfg(test)]
+mod bundle_tests;
+
+#[cfg(test)]
+mod post_date_tests;
+
+#[cfg(test)]
+mod frontmatter_tests;
+
+#[cfg(test)]
+mod data_tests;
+
+#[cfg(test)]
+mod translation_links_tests;
+
+#[cfg(test)]
+mod git_info_tests;
+
+#[cfg(test)]
+mod params_tests;
+
+#[cfg(test)]
+mod check_tests;
+
+#[cfg(test)]
+mod markdown_options_tests;
+
+#[cfg(test)]
+mod pages_tests;
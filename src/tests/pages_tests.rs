@@ -0,0 +1,114 @@
+use std::fs;
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use crate::app::AppConfig;
+use crate::shared::pages::{is_page, page_name, read_page, scan};
+
+fn write(path: &Path, contents: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+#[test]
+fn pages_lie_outside_sections_or_are_marked_in_them() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    write(&root.join("blog/index.yml"), "title: Blog\n");
+
+    let privacy = root.join("legal/privacy.md");
+    write(&privacy, "---\ntitle: Privacy\n---\n");
+    let about = root.join("blog/about.md");
+    write(&about, "---\ntitle: About\nlayout: page\n---\n");
+    let dated_name = root.join("blog/2025-01-05-hello.md");
+    write(&dated_name, "---\ntitle: Hello\n---\n");
+    let dated_frontmatter = root.join("blog/evergreen.md");
+    write(&dated_frontmatter, "---\ntitle: A\ndate: 2025-01-05\n---\n");
+    let bundle = root.join("blog/trip/index.md");
+    write(&bundle, "---\ntitle: Trip\ndate: 2025-01-05\n---\n");
+
+    assert!(is_page(&privacy).unwrap());
+    assert!(is_page(&about).unwrap());
+    assert!(!is_page(&dated_name).unwrap());
+    assert!(!is_page(&dated_frontmatter).unwrap());
+    assert!(!is_page(&bundle).unwrap());
+}
+
+#[test]
+fn undated_post_in_a_section_stays_a_post() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    write(&root.join("blog/index.yml"), "title: Blog\n");
+
+    // A misspelled `date` key leaves the post undated, which is reported when it loads
+    let typo = root.join("blog/lost-date.md");
+    write(&typo, "---\ntitle: Lost\ndaet: 2025-01-05\n---\n");
+    assert!(!is_page(&typo).unwrap());
+
+    let broken = root.join("blog/broken.md");
+    write(&broken, "---\ntitle: [\n---\n");
+    assert!(is_page(&broken).is_err());
+}
+
+#[test]
+fn page_names_follow_the_path_under_contents() {
+    let root = Path::new("contents");
+    let name = |path: &str| page_name(root, &root.join(path));
+
+    assert_eq!(name("about.md"), (None, "about".to_string()));
+    assert_eq!(
+        name("legal/privacy.md"),
+        (None, "legal/privacy".to_string())
+    );
+    assert_eq!(name("legal/index.md"), (None, "legal".to_string()));
+    assert_eq!(
+        name("en/about.md"),
+        (Some("en".to_string()), "about".to_string())
+    );
+    assert_eq!(
+        name("en/legal/privacy.md"),
+        (Some("en".to_string()), "legal/privacy".to_string())
+    );
+}
+
+#[test]
+fn read_page_renders_the_body_into_the_page_layout() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    let path = root.join("legal/privacy.md");
+    write(
+        &path,
+        "---\ntitle: Privacy\ndescription: How we use data\n---\n\n## Cookies\n\nNone at all.\n",
+    );
+
+    let page = read_page(root, &path, &AppConfig::default()).unwrap();
+
+    assert_eq!(page.page_name, "legal/privacy");
+    assert_eq!(page.title, "Privacy");
+    assert_eq!(page.layout.as_deref(), Some("page"));
+    let content = page.content.unwrap();
+    assert!(content.contains("Cookies</h2>"));
+    assert!(content.contains("<p>None at all.</p>"));
+}
+
+#[test]
+fn scan_keeps_a_chosen_layout_and_skips_posts() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    write(
+        &root.join("about.md"),
+        "---\ntitle: About\nlayout: about\n---\n",
+    );
+    write(&root.join("blog/index.yml"), "title: Blog\n");
+    write(
+        &root.join("blog/2025-01-05-hello.md"),
+        "---\ntitle: Hello\n---\n",
+    );
+
+    let pages = scan(root, &AppConfig::default()).unwrap();
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].page_name, "about");
+    assert_eq!(pages[0].layout.as_deref(), Some("about"));
+}
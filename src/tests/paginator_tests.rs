@@ -0,0 +1,51 @@
+use crate::app::{AppConfig, Pagination};
+use crate::generator::paginator::{page_name_for, page_size, Paginator};
+
+fn make_app() -> AppConfig {
+    let mut cfg = AppConfig::default();
+    cfg.languages.installed_languages = vec!["th".to_string(), "en".to_string()];
+    cfg.languages.default_language = "th".to_string();
+    cfg
+}
+
+#[test]
+fn listing_is_split_into_numbered_pages() {
+    let app = make_app();
+    let pagers = Paginator::paginate(&app, "en", "articles", 25, 10);
+
+    assert_eq!(pagers.len(), 3);
+    assert_eq!(pagers[0].url, "/en/articles/");
+    assert_eq!(pagers[0].prev, None);
+    assert_eq!(pagers[0].next.as_deref(), Some("/en/articles/page/2/"));
+    assert_eq!(pagers[2].prev.as_deref(), Some("/en/articles/page/2/"));
+    assert_eq!(pagers[2].next, None);
+    assert!(pagers[1].pages[1].current);
+
+    let items: Vec<usize> = (0..25).collect();
+    assert_eq!(pagers[2].slice(&items), &[20, 21, 22, 23, 24]);
+    assert_eq!(pagers[2].page_name(), "articles/page/3");
+}
+
+#[test]
+fn index_pages_live_under_root_page_directory() {
+    assert_eq!(page_name_for("index", 1), "index");
+    assert_eq!(page_name_for("index", 2), "page/2");
+    assert_eq!(page_name_for("tags/seo", 4), "tags/seo/page/4");
+
+    let pagers = Paginator::paginate(&make_app(), "th", "index", 11, 10);
+    assert_eq!(pagers[0].url, "/");
+    assert_eq!(pagers[1].url, "/page/2/");
+}
+
+#[test]
+fn zero_page_size_keeps_a_single_page() {
+    let mut app = make_app();
+    app.pagination = Some(Pagination { page_size: Some(5) });
+    assert_eq!(page_size(&app, None), 5);
+    assert_eq!(page_size(&app, Some(0)), 0);
+
+    let pagers = Paginator::paginate(&app, "th", "seo", 12, 0);
+    assert_eq!(pagers.len(), 1);
+    assert_eq!(pagers[0].slice(&[1, 2, 3]).len(), 3);
+    assert_eq!(pagers[0].next, None);
+}
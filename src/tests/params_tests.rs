@@ -0,0 +1,111 @@
+use std::fs;
+use std::path::Path;
+
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::app::{AppConfig, Params};
+use crate::constants::content::READING_TIME_WPM;
+use crate::posts::Post;
+use crate::shared::markdown_to_html::options::options;
+use crate::shared::params::{validate, Params as ParamMap};
+
+fn app_with_schema(schema: &str) -> AppConfig {
+    let params: Params = toml::from_str(schema).unwrap();
+    AppConfig {
+        params: Some(params),
+        ..Default::default()
+    }
+}
+
+fn params(value: serde_json::Value) -> ParamMap {
+    value.as_object().unwrap().clone()
+}
+
+#[test]
+fn unknown_keys_and_params_map_reach_the_post() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("2025-07-03-guide.md");
+    fs::write(
+        &path,
+        "---\ntitle: Guide\nhero_color: \"#ff0\"\ndifficulty: easy\n\
+         params:\n  cta_url: /buy/\n  difficulty: advanced\n---\n\nBody\n",
+    )
+    .unwrap();
+
+    let post = Post::open(
+        &path,
+        &AppConfig::default(),
+        Path::new("seo"),
+        "/{section}/{slug}.html",
+        "",
+        &options(&AppConfig::default(), None),
+        READING_TIME_WPM,
+    )
+    .unwrap();
+
+    assert_eq!(post.params["hero_color"], json!("#ff0"));
+    assert_eq!(post.params["cta_url"], json!("/buy/"));
+    // The params map wins over a top-level key of the same name
+    assert_eq!(post.params["difficulty"], json!("advanced"));
+}
+
+#[test]
+fn schema_checks_types_and_allowed_values() {
+    let app = app_with_schema(
+        r#"
+        [schema]
+        hero_color = "string"
+        rating = "integer"
+        launch = "date"
+        difficulty = { type = "string", values = ["beginner", "advanced"] }
+        "#,
+    );
+
+    assert!(validate(
+        &params(json!({
+            "hero_color": "#ff0",
+            "rating": 4,
+            "launch": "2025-07-03",
+            "difficulty": "beginner",
+            "unlisted": [1, 2],
+        })),
+        &app
+    )
+    .is_ok());
+
+    let error = validate(
+        &params(json!({ "rating": 4.5, "difficulty": "expert", "launch": "soon" })),
+        &app,
+    )
+    .unwrap_err();
+    assert!(error.contains("`rating` should be an integer, found 4.5"));
+    assert!(error.contains(r#"`difficulty` should be one of "beginner", "advanced""#));
+    assert!(error.contains("`launch` should be a date"));
+}
+
+#[test]
+fn post_with_wrong_param_type_fails_to_load() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("2025-07-03-guide.md");
+    fs::write(
+        &path,
+        "---\ntitle: Guide\nparams:\n  rating: five\n---\n\nBody\n",
+    )
+    .unwrap();
+
+    let error = Post::open(
+        &path,
+        &app_with_schema("[schema]\nrating = \"number\"\n"),
+        Path::new("seo"),
+        "/{section}/{slug}.html",
+        "",
+        &options(&AppConfig::default(), None),
+        READING_TIME_WPM,
+    )
+    .unwrap_err();
+
+    let message = error.to_string();
+    assert!(message.contains("2025-07-03-guide.md"));
+    assert!(message.contains("`rating` should be a number"));
+}
@@ -0,0 +1,82 @@
+use crate::shared::permalink::{expand, output_path, PermalinkParts};
+
+fn parts<'a>(section: &'a str, slug: &'a str) -> PermalinkParts<'a> {
+    PermalinkParts {
+        section,
+        year: 2025,
+        month: 4,
+        day: 7,
+        slug,
+        category: Some("Technical SEO"),
+    }
+}
+
+#[test]
+fn default_pattern_keeps_html_urls() {
+    let pattern = "/{section}/{slug}.html";
+    assert_eq!(
+        expand(pattern, &parts("seo", "on-page"), true).unwrap(),
+        "/seo/on-page.html"
+    );
+    // Root posts drop the empty section segment
+    assert_eq!(
+        expand(pattern, &parts("", "on-page"), true).unwrap(),
+        "/on-page.html"
+    );
+}
+
+#[test]
+fn dated_and_pretty_patterns() {
+    assert_eq!(
+        expand(
+            "/{section}/{year}/{month}/{day}/{slug}/",
+            &parts("seo", "a"),
+            false
+        )
+        .unwrap(),
+        "/seo/2025/04/07/a/"
+    );
+    assert_eq!(
+        expand("/{category}/{slug}", &parts("seo", "a"), true).unwrap(),
+        "/technical-seo/a/"
+    );
+    assert_eq!(
+        expand("/{category}/{slug}", &parts("seo", "a"), false).unwrap(),
+        "/technical-seo/a.html"
+    );
+}
+
+#[test]
+fn unknown_tokens_are_rejected() {
+    let err = expand("/{sectoin}/{slug}/", &parts("seo", "a"), true).unwrap_err();
+    assert!(err.to_string().contains("{sectoin}"));
+    assert!(expand("/{slug", &parts("seo", "a"), true).is_err());
+}
+
+#[test]
+fn output_paths_follow_urls() {
+    assert_eq!(output_path("/seo/on-page.html"), "seo/on-page.html");
+    assert_eq!(output_path("/seo/2025/04/a/"), "seo/2025/04/a/index.html");
+}
+
+#[test]
+fn default_pattern_keeps_the_html_urls() {
+    let pattern = crate::constants::paths::DEFAULT_PERMALINK;
+    for use_directory in [true, false] {
+        let url = expand(pattern, &parts("seo", "on-page"), use_directory).unwrap();
+        assert_eq!(url, "/seo/on-page.html");
+        assert_eq!(output_path(&url), "seo/on-page.html");
+    }
+}
+
+#[test]
+fn pattern_without_an_ending_follows_use_directory_structure() {
+    let pattern = "/{section}/{slug}";
+    let pretty = expand(pattern, &parts("seo", "on-page"), true).unwrap();
+    assert_eq!(pretty, "/seo/on-page/");
+    assert_eq!(output_path(&pretty), "seo/on-page/index.html");
+
+    let file = expand(pattern, &parts("seo", "on-page"), false).unwrap();
+    assert_eq!(file, "/seo/on-page.html");
+    assert_eq!(output_path(&file), "seo/on-page.html");
+}
@@ -0,0 +1,81 @@
+use std::fs;
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use crate::app::AppConfig;
+use crate::constants::content::READING_TIME_WPM;
+use crate::posts::Post;
+use crate::shared::markdown_to_html::options::options;
+use crate::shared::validation::{FileValidator, ValidationConfig};
+
+fn open(dir: &Path, name: &str, frontmatter: &str) -> eyre::Result<Post> {
+    let path = dir.join(name);
+    fs::write(
+        &path,
+        format!("---\ntitle: \"Post\"\n{frontmatter}---\n\nBody\n"),
+    )
+    .unwrap();
+    Post::open(
+        &path,
+        &AppConfig::default(),
+        Path::new("docs"),
+        "/{section}/{year}/{slug}/",
+        "",
+        &options(&AppConfig::default(), None),
+        READING_TIME_WPM,
+    )
+}
+
+#[test]
+fn undated_filename_uses_the_frontmatter_date() {
+    let temp = TempDir::new().unwrap();
+    let post = open(
+        temp.path(),
+        "evergreen-guide.md",
+        "date: 2024-12-31T23:30:00+07:00\n",
+    )
+    .unwrap();
+
+    // The calendar date is taken in the date's own zone
+    assert_eq!((post.year, post.month, post.day), (2024, 12, 31));
+    assert_eq!(post.url, "/docs/2024/evergreen-guide/");
+    assert_eq!(post.published, "2024-12-31T23:30:00+07:00");
+}
+
+#[test]
+fn frontmatter_date_wins_over_the_filename() {
+    let temp = TempDir::new().unwrap();
+    let post = open(
+        temp.path(),
+        "2025-02-24-imported.md",
+        "date_published: 2025-01-29\n",
+    )
+    .unwrap();
+    assert_eq!((post.year, post.month, post.day), (2025, 1, 29));
+    assert_eq!(post.url, "/docs/2025/imported/");
+}
+
+#[test]
+fn undated_post_without_a_date_is_an_error() {
+    let temp = TempDir::new().unwrap();
+    let err = open(temp.path(), "no-date.md", "").unwrap_err();
+    assert!(err.to_string().contains("has no date"));
+
+    let err = open(temp.path(), "bad-date.md", "date: last tuesday\n").unwrap_err();
+    assert!(err.to_string().contains("Invalid date"));
+}
+
+#[test]
+fn validator_accepts_undated_names_with_a_date() {
+    let temp = TempDir::new().unwrap();
+    let validator = FileValidator::new(ValidationConfig::default());
+
+    let dated = temp.path().join("evergreen-guide.md");
+    fs::write(&dated, "---\ntitle: \"A\"\ndate: 2025-01-05\n---\n").unwrap();
+    assert!(validator.validate_file(&dated).unwrap().is_valid);
+
+    let undated = temp.path().join("loose-note.md");
+    fs::write(&undated, "---\ntitle: \"A\"\n---\n").unwrap();
+    assert!(!validator.validate_file(&undated).unwrap().is_valid);
+}
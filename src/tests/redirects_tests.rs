@@ -0,0 +1,95 @@
+use crate::app::AppConfig;
+use crate::blog::Article;
+use crate::generator::redirects::{stub_output_path, Redirects};
+use crate::posts::Post;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+fn make_app(redirects: &[(&str, &str)]) -> AppConfig {
+    let mut cfg = AppConfig::default();
+    cfg.languages.installed_languages = vec!["th".to_string(), "en".to_string()];
+    cfg.languages.default_language = "th".to_string();
+    cfg.redirects = Some(
+        redirects
+            .iter()
+            .map(|(from, to)| ((*from).to_string(), (*to).to_string()))
+            .collect::<BTreeMap<_, _>>(),
+    );
+    cfg
+}
+
+fn make_article(lang: &str, aliases: &[&str]) -> Article {
+    let post = Post {
+        title: "New".to_string(),
+        url: "/seo/new.html".to_string(),
+        aliases: aliases.iter().map(ToString::to_string).collect(),
+        ..Default::default()
+    };
+    Article {
+        layout: "articles".to_string(),
+        title: "SEO".to_string(),
+        description: String::new(),
+        image: String::new(),
+        prefix: PathBuf::from("seo"),
+        app_domain: String::new(),
+        posts: vec![post],
+        link_text: "SEO".to_string(),
+        keywords: Vec::new(),
+        author: String::new(),
+        author_url: String::new(),
+        author_email: String::new(),
+        lang: lang.to_string(),
+        draft: false,
+        date_modified: String::new(),
+        date_published: String::new(),
+        category: String::new(),
+        paginate: None,
+        scheduled: Vec::new(),
+        expired: Vec::new(),
+        params: Default::default(),
+    }
+}
+
+#[test]
+fn collects_aliases_and_config_redirects() {
+    let app = make_app(&[("blogs/", "/articles/")]);
+    let articles = [
+        make_article("th", &["/old"]),
+        make_article("en", &["/en/old/"]),
+    ];
+
+    let redirects = Redirects::collect(&app, &articles, false).unwrap();
+    let pairs: Vec<(&str, &str)> = redirects
+        .redirects()
+        .iter()
+        .map(|r| (r.from.as_str(), r.to.as_str()))
+        .collect();
+    assert_eq!(
+        pairs,
+        vec![
+            ("/blogs", "/articles/"),
+            ("/en/old", "/en/seo/new.html"),
+            ("/old", "/seo/new.html"),
+        ]
+    );
+
+    let file = redirects.redirects_file("/404  /  404\n");
+    assert!(file.starts_with("/404  /  404\n"));
+    assert!(file.ends_with("/old /seo/new.html 301\n"));
+}
+
+#[test]
+fn conflicting_and_self_redirects_fail() {
+    let app = make_app(&[("/old", "/elsewhere/")]);
+    let err = Redirects::collect(&app, &[make_article("th", &["/old"])], false).unwrap_err();
+    assert!(err.to_string().contains("/old"));
+
+    let app = make_app(&[]);
+    assert!(Redirects::collect(&app, &[make_article("th", &["/seo/new.html"])], false).is_err());
+}
+
+#[test]
+fn stub_paths() {
+    assert_eq!(stub_output_path("/old"), "old/index.html");
+    assert_eq!(stub_output_path("/2025/02/old.html"), "2025/02/old.html");
+}
@@ -0,0 +1,82 @@
+use crate::app::{AppConfig, Related};
+use crate::generator::related::RelatedPosts;
+use crate::posts::Post;
+
+fn make_post(slug: &str, title: &str, tags: &[&str], category: Option<&str>) -> Post {
+    Post {
+        title: title.to_string(),
+        url: format!("/blog/{slug}.html"),
+        year: 2025,
+        month: 1,
+        day: 1,
+        tags: Some(tags.iter().map(ToString::to_string).collect()),
+        category: category.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+fn urls<'a>(posts: &[&'a Post]) -> Vec<&'a str> {
+    posts.iter().map(|p| p.url.as_str()).collect()
+}
+
+#[test]
+fn shared_tags_outrank_shared_category() {
+    let app = AppConfig::default();
+    let posts = [
+        make_post("a", "Alpha", &["rust", "seo"], Some("dev")),
+        make_post("b", "Bravo", &["rust", "seo"], None),
+        make_post("c", "Charlie", &[], Some("dev")),
+        make_post("d", "Delta", &["cooking"], Some("food")),
+    ];
+    let refs: Vec<&Post> = posts.iter().collect();
+
+    let related = RelatedPosts::new(&app).compute(&refs);
+    assert_eq!(
+        urls(&related["/blog/a.html"]),
+        vec!["/blog/b.html", "/blog/c.html"]
+    );
+}
+
+#[test]
+fn text_similarity_works_for_thai_without_tags() {
+    let app = AppConfig::default();
+    let posts = [
+        make_post("a", "วิธีติดตั้งโปรแกรมบนเครื่อง", &[], None),
+        make_post("b", "วิธีติดตั้งโปรแกรมบนเซิร์ฟเวอร์", &[], None),
+        make_post("c", "Cooking pasta at home", &[], None),
+    ];
+    let refs: Vec<&Post> = posts.iter().collect();
+
+    let related = RelatedPosts::new(&app).compute(&refs);
+    assert_eq!(urls(&related["/blog/a.html"]), vec!["/blog/b.html"]);
+}
+
+#[test]
+fn limit_and_drafts_are_respected() {
+    let mut app = AppConfig {
+        related: Some(Related {
+            limit: Some(1),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let mut draft = make_post("b", "Bravo", &["rust"], None);
+    draft.draft = true;
+    let posts = [
+        make_post("a", "Alpha", &["rust"], None),
+        draft,
+        make_post("c", "Charlie", &["rust"], None),
+        make_post("d", "Delta", &["rust"], None),
+    ];
+    let refs: Vec<&Post> = posts.iter().collect();
+
+    let related = RelatedPosts::new(&app).compute(&refs);
+    assert_eq!(related["/blog/a.html"].len(), 1);
+    assert!(related.values().flatten().all(|p| !p.draft));
+
+    app.related = Some(Related {
+        limit: Some(0),
+        ..Default::default()
+    });
+    assert!(RelatedPosts::new(&app).compute(&refs).is_empty());
+}
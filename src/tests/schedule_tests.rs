@@ -0,0 +1,117 @@
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use crate::app::AppConfig;
+use crate::constants::content::READING_TIME_WPM;
+use crate::generator::schedule::{parse_date, post_status, BuildOptions, PostStatus};
+use crate::posts::Post;
+use crate::shared::markdown_to_html::options::options;
+
+fn options_at(now: &str, future: bool) -> BuildOptions {
+    BuildOptions {
+        future,
+        drafts: false,
+        now: parse_date(now).expect("valid date").to_utc(),
+    }
+}
+
+fn make_post(published: &str) -> Post {
+    Post {
+        title: "p".to_string(),
+        published: published.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn future_posts_are_held_back_unless_future_is_set() {
+    let post = make_post("2025-06-02T00:00:00+00:00");
+
+    assert_eq!(
+        post_status(&post, &options_at("2025-06-01", false)),
+        PostStatus::Scheduled
+    );
+    assert_eq!(
+        post_status(&post, &options_at("2025-06-01", true)),
+        PostStatus::Published
+    );
+    assert_eq!(
+        post_status(&post, &options_at("2025-06-02", false)),
+        PostStatus::Published
+    );
+}
+
+#[test]
+fn publish_time_keeps_its_zone() {
+    let post = make_post("2025-06-01T09:00:00+07:00");
+
+    // 09:00 in Bangkok is 02:00 UTC
+    assert_eq!(
+        post_status(&post, &options_at("2025-06-01 01:00:00", false)),
+        PostStatus::Scheduled
+    );
+    assert_eq!(
+        post_status(&post, &options_at("2025-06-01 03:00:00", false)),
+        PostStatus::Published
+    );
+}
+
+#[test]
+fn expired_posts_are_removed_even_with_future() {
+    let mut post = make_post("2025-01-01T00:00:00+00:00");
+    post.expiry_date = Some("2025-03-01".to_string());
+
+    assert_eq!(
+        post_status(&post, &options_at("2025-02-28", false)),
+        PostStatus::Published
+    );
+    assert_eq!(
+        post_status(&post, &options_at("2025-03-01", true)),
+        PostStatus::Expired
+    );
+}
+
+#[test]
+fn build_flags_are_read_from_args() {
+    let args = vec!["--drafts".to_string()];
+    let options = BuildOptions::from_args(&args);
+    assert!(options.drafts);
+    assert!(!options.future);
+
+    let options = BuildOptions::from_args(&[]);
+    assert!(!options.drafts);
+}
+
+#[test]
+fn post_is_scheduled_by_the_date_it_shows() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("launch.md");
+    std::fs::write(
+        &path,
+        "---\ntitle: Launch\ndate: 2025-06-10\ndate_published: 2025-06-01\n---\n\nBody\n",
+    )
+    .unwrap();
+    let app = AppConfig::default();
+    let post = Post::open(
+        &path,
+        &app,
+        Path::new("blog"),
+        "/{section}/{slug}/",
+        "",
+        &options(&app, None),
+        READING_TIME_WPM,
+    )
+    .unwrap();
+
+    // `date` wins over `date_published` for both the shown date and the schedule
+    assert_eq!(post.day, 10);
+    assert_eq!(
+        post_status(&post, &options_at("2025-06-05", false)),
+        PostStatus::Scheduled
+    );
+    assert_eq!(
+        post_status(&post, &options_at("2025-06-10", false)),
+        PostStatus::Published
+    );
+}
@@ -0,0 +1,100 @@
+use crate::app::AppConfig;
+use crate::blog::Article;
+use crate::generator::series::{date_neighbours, Series};
+use crate::posts::Post;
+use std::path::PathBuf;
+
+fn make_app() -> AppConfig {
+    let mut cfg = AppConfig::default();
+    cfg.languages.installed_languages = vec!["th".to_string(), "en".to_string()];
+    cfg.languages.default_language = "th".to_string();
+    cfg
+}
+
+fn make_post(slug: &str, day: u32, series: Option<(&str, u32)>) -> Post {
+    Post {
+        title: slug.to_string(),
+        url: format!("/docs/{slug}.html"),
+        year: 2025,
+        month: 1,
+        day,
+        series: series.map(|(name, _)| name.to_string()),
+        series_part: series.map(|(_, part)| part),
+        ..Default::default()
+    }
+}
+
+fn make_article(posts: Vec<Post>) -> Article {
+    Article {
+        layout: "articles".to_string(),
+        title: "Docs".to_string(),
+        description: String::new(),
+        image: String::new(),
+        prefix: PathBuf::from("docs"),
+        app_domain: String::new(),
+        posts,
+        link_text: "Docs".to_string(),
+        keywords: Vec::new(),
+        author: String::new(),
+        author_url: String::new(),
+        author_email: String::new(),
+        lang: "en".to_string(),
+        draft: false,
+        date_modified: String::new(),
+        date_published: String::new(),
+        category: String::new(),
+        paginate: None,
+        scheduled: Vec::new(),
+        expired: Vec::new(),
+        params: Default::default(),
+    }
+}
+
+#[test]
+fn series_parts_follow_series_part_over_date() {
+    let app = make_app();
+    // Sections keep posts newest first
+    let article = make_article(vec![
+        make_post("deploy", 3, Some(("User Guide", 2))),
+        make_post("unrelated", 2, None),
+        make_post("install", 1, Some(("User Guide", 1))),
+        make_post("intro", 1, Some(("User Guide", 0))),
+    ]);
+
+    let series = Series::build_all(&app, &[&article], "en");
+    let guide = series.get("user-guide").expect("series is grouped by slug");
+    assert_eq!(guide.url, "/en/series/user-guide/");
+
+    let nav = guide
+        .nav_for(&article.posts[2], &app, "en")
+        .expect("post is part of the series");
+    assert_eq!(nav.index, 2);
+    assert_eq!(nav.total, 3);
+    assert_eq!(
+        nav.prev.map(|p| p.url).as_deref(),
+        Some("/en/docs/intro.html")
+    );
+    assert_eq!(
+        nav.next.map(|p| p.url).as_deref(),
+        Some("/en/docs/deploy.html")
+    );
+    assert!(guide.nav_for(&article.posts[1], &app, "en").is_none());
+}
+
+#[test]
+fn date_neighbours_link_older_and_newer_posts() {
+    let app = make_app();
+    let article = make_article(vec![
+        make_post("newest", 3, None),
+        make_post("middle", 2, None),
+        make_post("oldest", 1, None),
+    ]);
+
+    let (older, newer) = date_neighbours(&article, &article.posts[1], &app, "th");
+    assert_eq!(older.map(|p| p.url).as_deref(), Some("/docs/oldest.html"));
+    assert_eq!(newer.map(|p| p.url).as_deref(), Some("/docs/newest.html"));
+
+    let (older, newer) = date_neighbours(&article, &article.posts[0], &app, "th");
+    assert!(newer.is_none());
+    assert_eq!(older.map(|p| p.title).as_deref(), Some("middle"));
+}
@@ -0,0 +1,113 @@
+use crate::app::AppConfig;
+use crate::generator::sitemap::{
+    lists_drafts, normalize_lastmod, output_path_to_url, SitemapBuilder,
+};
+use crate::posts::Post;
+
+fn make_app() -> AppConfig {
+    let mut cfg = AppConfig::default();
+    cfg.app_info.app_domain = "https://example.com".to_string();
+    cfg.languages.installed_languages = vec!["th".to_string(), "en".to_string()];
+    cfg.languages.default_language = "th".to_string();
+    cfg
+}
+
+#[test]
+fn output_paths_map_to_public_urls() {
+    assert_eq!(output_path_to_url("index.html"), "/");
+    assert_eq!(output_path_to_url("en/index.html"), "/en/");
+    assert_eq!(output_path_to_url("about/index.html"), "/about/");
+    assert_eq!(
+        output_path_to_url("seo/what-is-seo.html"),
+        "/seo/what-is-seo.html"
+    );
+}
+
+#[test]
+fn lastmod_is_normalised_to_w3c_datetime() {
+    assert_eq!(
+        normalize_lastmod("2025-01-29T00:40:04-07:00").as_deref(),
+        Some("2025-01-29T00:40:04-07:00")
+    );
+    assert_eq!(
+        normalize_lastmod("2025-07-03").as_deref(),
+        Some("2025-07-03")
+    );
+    assert_eq!(normalize_lastmod("1735689600"), None);
+    assert_eq!(normalize_lastmod(""), None);
+}
+
+#[test]
+fn urlset_contains_hreflang_alternates_for_translated_pages() {
+    let app = make_app();
+    let mut sitemap = SitemapBuilder::new(&app);
+    sitemap.add_output("th", "about/index.html", Some("2025-01-01"));
+    sitemap.add_output("en", "en/about/index.html", None);
+    sitemap.add_output("th", "eeat-guide.html", None);
+
+    let th = sitemap.render_urlset("th");
+    assert!(th.contains("<loc>https://example.com/about/</loc>"));
+    assert!(th.contains(
+        r#"<xhtml:link rel="alternate" hreflang="en" href="https://example.com/en/about/"/>"#
+    ));
+    assert!(th.contains(
+        r#"<xhtml:link rel="alternate" hreflang="x-default" href="https://example.com/about/"/>"#
+    ));
+    assert!(th.contains("<lastmod>2025-01-01</lastmod>"));
+    assert!(!th.contains("https://example.com/en/about/</loc>"));
+
+    let index = sitemap.render_index();
+    assert!(index.contains("<loc>https://example.com/sitemap-th.xml</loc>"));
+    assert!(index.contains("<loc>https://example.com/en/sitemap.xml</loc>"));
+}
+
+#[test]
+fn translation_key_pairs_pages_with_different_paths() {
+    let app = make_app();
+    let mut sitemap = SitemapBuilder::new(&app);
+    sitemap.add_output_with_key("th", "docs/kan-tit-tang.html", None, Some("install"));
+    sitemap.add_output_with_key("en", "en/docs/installation.html", None, Some("install"));
+
+    let en = sitemap.render_urlset("en");
+    assert!(en.contains(
+        r#"<xhtml:link rel="alternate" hreflang="th" href="https://example.com/docs/kan-tit-tang.html"/>"#
+    ));
+    assert!(en.contains(
+        r#"<xhtml:link rel="alternate" hreflang="x-default" href="https://example.com/docs/kan-tit-tang.html"/>"#
+    ));
+}
+
+#[test]
+fn urls_with_thai_slugs_are_percent_encoded() {
+    let app = make_app();
+    let mut sitemap = SitemapBuilder::new(&app);
+    sitemap.add_output("th", "tags/คู่มือ/index.html", None);
+    sitemap.add_output("en", "en/tags/คู่มือ/index.html", None);
+
+    let th = sitemap.render_urlset("th");
+    assert!(th.contains(
+        "<loc>https://example.com/tags/%E0%B8%84%E0%B8%B9%E0%B9%88%E0%B8%A1%E0%B8%B7%E0%B8%AD/</loc>"
+    ));
+    assert!(th.contains(
+        r#"hreflang="en" href="https://example.com/en/tags/%E0%B8%84%E0%B8%B9%E0%B9%88%E0%B8%A1%E0%B8%B7%E0%B8%AD/"/>"#
+    ));
+    assert!(!th.contains("คู่มือ"));
+}
+
+#[test]
+fn listings_of_drafts_stay_out_of_the_sitemap() {
+    let published = Post::default();
+    let draft = Post {
+        draft: true,
+        ..Default::default()
+    };
+
+    // Taxonomy terms and authors list references
+    assert!(!lists_drafts(&[&published]));
+    assert!(lists_drafts(&[&published, &draft]));
+
+    // A series owns its posts
+    let series = vec![published, draft];
+    assert!(!lists_drafts(&series[..1]));
+    assert!(lists_drafts(&series));
+}
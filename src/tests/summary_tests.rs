@@ -0,0 +1,54 @@
+use crate::shared::summary::{auto_summary, split_at_separator};
+use crate::shared::utils::extract_excerpt;
+
+#[test]
+fn separator_splits_the_markdown() {
+    let markdown = "First paragraph.\n\n<!-- more -->\n\nRest of the post.\n";
+    let (before, after) = split_at_separator(markdown).unwrap();
+    assert_eq!(before, "First paragraph.\n\n");
+    assert_eq!(after, "\nRest of the post.\n");
+
+    assert!(split_at_separator("No separator, only `<!--more-->` inline.\n").is_none());
+}
+
+#[test]
+fn separator_in_a_code_fence_is_not_split_at() {
+    let markdown =
+        "How to mark a summary:\n\n```html\n<!--more-->\n~~~\n```\n\nIntro.\n<!--more-->\nRest.\n";
+    let (before, after) = split_at_separator(markdown).unwrap();
+    assert_eq!(
+        before,
+        "How to mark a summary:\n\n```html\n<!--more-->\n~~~\n```\n\nIntro.\n"
+    );
+    assert_eq!(after, "Rest.\n");
+
+    assert!(split_at_separator("~~~\n<!-- more -->\n~~~\n").is_none());
+}
+
+#[test]
+fn excerpt_keeps_thai_marks_with_their_consonant() {
+    // "ที่" is one grapheme of three code points
+    assert_eq!(extract_excerpt("ที่นี่", 1), "ที่...");
+    assert_eq!(extract_excerpt("ที่นี่", 2), "ที่นี่");
+    assert_eq!(extract_excerpt("one two three four", 9), "one two...");
+}
+
+#[test]
+fn excerpt_cuts_at_the_last_space_as_before() {
+    assert_eq!(extract_excerpt("a bcdefghijk", 10), "a...");
+    assert_eq!(extract_excerpt("one two\tthree", 12), "one...");
+    assert_eq!(extract_excerpt("one  two three", 10), "one  two...");
+    assert_eq!(extract_excerpt("abcdefghij", 5), "abcde...");
+    assert_eq!(extract_excerpt("short", 5), "short");
+}
+
+#[test]
+fn auto_summary_is_plain_and_escaped() {
+    let (summary, html) = auto_summary(
+        "<h2>Tips &amp; tricks</h2><p>Use <code>&lt;b&gt;</code></p>",
+        160,
+    );
+    assert_eq!(summary, "Tips & tricks Use <b>");
+    assert_eq!(html, "<p>Tips &amp; tricks Use &lt;b&gt;</p>");
+    assert_eq!(auto_summary("", 160), (String::new(), String::new()));
+}
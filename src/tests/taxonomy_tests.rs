@@ -0,0 +1,107 @@
+use crate::app::{AppConfig, Taxonomies};
+use crate::blog::Article;
+use crate::generator::taxonomy::{configured_taxonomies, terms_from_frontmatter, Taxonomy};
+use crate::posts::Post;
+use crate::shared::url::slugify;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+fn make_app(names: &[&str]) -> AppConfig {
+    let mut cfg = AppConfig::default();
+    cfg.languages.installed_languages = vec!["th".to_string(), "en".to_string()];
+    cfg.languages.default_language = "th".to_string();
+    cfg.taxonomies = Some(Taxonomies {
+        enabled: None,
+        names: Some(names.iter().map(ToString::to_string).collect()),
+    });
+    cfg
+}
+
+fn make_post(url: &str, taxonomies: &[(&str, &[&str])]) -> Post {
+    Post {
+        url: url.to_string(),
+        year: 2025,
+        taxonomies: taxonomies
+            .iter()
+            .map(|(name, terms)| {
+                (
+                    (*name).to_string(),
+                    terms.iter().map(ToString::to_string).collect(),
+                )
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn slugify_keeps_thai_marks_and_collapses_separators() {
+    assert_eq!(slugify("SEO Tips"), "seo-tips");
+    assert_eq!(slugify("  Rust / WebAssembly  "), "rust-webassembly");
+    assert_eq!(slugify("การตลาด ออนไลน์"), "การตลาด-ออนไลน์");
+}
+
+#[test]
+fn custom_taxonomies_read_their_own_frontmatter_key() {
+    let app = make_app(&["tags", "categories", "difficulty"]);
+    let mut extra = BTreeMap::new();
+    extra.insert(
+        "difficulty".to_string(),
+        serde_yaml::Value::String("beginner".to_string()),
+    );
+    let tags = vec!["seo".to_string()];
+    let category = "Guides".to_string();
+
+    let terms = terms_from_frontmatter(&app, Some(&tags), Some(&category), &extra);
+    assert_eq!(terms.get("tags"), Some(&vec!["seo".to_string()]));
+    assert_eq!(terms.get("categories"), Some(&vec!["Guides".to_string()]));
+    assert_eq!(terms.get("difficulty"), Some(&vec!["beginner".to_string()]));
+}
+
+#[test]
+fn taxonomy_terms_are_counted_and_localised() {
+    let app = make_app(&["tags"]);
+    let article = Article {
+        layout: "articles".to_string(),
+        title: String::new(),
+        description: String::new(),
+        image: String::new(),
+        prefix: PathBuf::new(),
+        app_domain: String::new(),
+        posts: vec![
+            make_post("/a.html", &[("tags", &["SEO", "Rust"])]),
+            make_post("/b.html", &[("tags", &["seo"])]),
+        ],
+        link_text: String::new(),
+        keywords: Vec::new(),
+        author: String::new(),
+        author_url: String::new(),
+        author_email: String::new(),
+        lang: "en".to_string(),
+        draft: false,
+        date_modified: String::new(),
+        date_published: String::new(),
+        category: String::new(),
+        paginate: None,
+        scheduled: Vec::new(),
+        expired: Vec::new(),
+        params: Default::default(),
+    };
+
+    let taxonomy = Taxonomy::build(&app, "tags", &[&article], "en");
+    assert_eq!(taxonomy.url, "/en/tags/");
+    assert_eq!(taxonomy.terms[0].slug, "seo");
+    assert_eq!(taxonomy.terms[0].count, 2);
+    assert_eq!(taxonomy.terms[0].url, "/en/tags/seo/");
+    assert_eq!(taxonomy.terms[1].name, "Rust");
+}
+
+#[test]
+fn taxonomies_can_be_disabled() {
+    let mut app = make_app(&["tags"]);
+    app.taxonomies = Some(Taxonomies {
+        enabled: Some(false),
+        names: None,
+    });
+    assert!(configured_taxonomies(&app).is_empty());
+}
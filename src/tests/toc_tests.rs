@@ -0,0 +1,61 @@
+use crate::shared::markdown_to_html::comrak_custom::markdown_to_html_with_headings;
+use crate::shared::markdown_to_html::html_custom::Heading;
+use crate::shared::toc::{build, render};
+
+fn heading(level: u8, id: &str) -> Heading {
+    Heading {
+        level,
+        id: id.to_string(),
+        text: id.to_uppercase(),
+    }
+}
+
+#[test]
+fn headings_are_collected_with_their_ids() {
+    let options = comrak::Options {
+        extension: comrak::ExtensionOptions::builder()
+            .header_ids(String::new())
+            .build(),
+        ..comrak::Options::default()
+    };
+    let (html, headings) = markdown_to_html_with_headings(
+        "# Title\n\n## SEO คืออะไร\n\n## Setup\n\n## Setup\n",
+        &options,
+    );
+
+    assert!(html.contains("id=\"seo-คืออะไร\""));
+    let ids: Vec<&str> = headings.iter().map(|h| h.id.as_str()).collect();
+    assert_eq!(ids, ["title", "seo-คืออะไร", "setup", "setup-1"]);
+    assert_eq!(headings[1].text, "SEO คืออะไร");
+    assert_eq!(headings[1].level, 2);
+}
+
+#[test]
+fn headings_nest_by_level_within_range() {
+    let headings = [
+        heading(1, "title"),
+        heading(2, "a"),
+        heading(3, "a1"),
+        heading(4, "deep"),
+        heading(2, "b"),
+        heading(4, "b1"),
+    ];
+    let toc = build(&headings, 2, 4);
+
+    assert_eq!(toc.len(), 2);
+    assert_eq!(toc[0].children[0].id, "a1");
+    assert_eq!(toc[0].children[0].children[0].id, "deep");
+    // A skipped level still nests under the previous heading
+    assert_eq!(toc[1].children[0].id, "b1");
+
+    assert_eq!(
+        render(&build(&headings, 2, 2)),
+        "<ol><li><a href=\"#a\">A</a></li><li><a href=\"#b\">B</a></li></ol>"
+    );
+}
+
+#[test]
+fn too_few_headings_give_no_toc() {
+    assert!(build(&[heading(2, "only")], 2, 3).is_empty());
+    assert_eq!(render(&[]), "");
+}
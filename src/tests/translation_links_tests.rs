@@ -0,0 +1,142 @@
+use crate::app::AppConfig;
+use crate::blog::Article;
+use crate::generator::translation_links::{alternates, link_translations};
+use crate::posts::Post;
+use std::path::PathBuf;
+
+fn make_app() -> AppConfig {
+    let mut cfg = AppConfig::default();
+    cfg.languages.installed_languages = vec!["th".to_string(), "en".to_string()];
+    cfg.languages.default_language = "th".to_string();
+    cfg.app_info.app_domain = "https://example.com".to_string();
+    cfg
+}
+
+fn make_post(slug: &str, translation_key: Option<&str>) -> Post {
+    Post {
+        title: slug.to_string(),
+        url: format!("/docs/{slug}.html"),
+        translation_key: translation_key.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+fn make_article(lang: &str, title: &str, prefix: &str, posts: Vec<Post>) -> Article {
+    Article {
+        layout: "articles".to_string(),
+        title: title.to_string(),
+        description: String::new(),
+        image: String::new(),
+        prefix: PathBuf::from(prefix),
+        app_domain: String::new(),
+        posts,
+        link_text: String::new(),
+        keywords: Vec::new(),
+        author: String::new(),
+        author_url: String::new(),
+        author_email: String::new(),
+        lang: lang.to_string(),
+        draft: false,
+        date_modified: String::new(),
+        date_published: String::new(),
+        category: String::new(),
+        paginate: None,
+        scheduled: Vec::new(),
+        expired: Vec::new(),
+        params: Default::default(),
+    }
+}
+
+#[test]
+fn posts_pair_by_translation_key_or_matching_url() {
+    let app = make_app();
+    let mut articles = vec![
+        make_article(
+            "th",
+            "เอกสาร",
+            "docs",
+            vec![
+                make_post("kan-tit-tang", Some("install")),
+                make_post("templates", None),
+            ],
+        ),
+        make_article(
+            "en",
+            "Docs",
+            "docs",
+            vec![
+                make_post("installation", Some("install")),
+                make_post("templates", None),
+            ],
+        ),
+    ];
+    link_translations(&mut articles, &app);
+
+    let install = &articles[1].posts[0].translations;
+    assert_eq!(install.len(), 2);
+    assert_eq!(install[0].lang, "th");
+    assert_eq!(install[0].url, "/docs/kan-tit-tang.html");
+    assert_eq!(install[0].title, "kan-tit-tang");
+    assert!(!install[0].current && !install[0].fallback);
+    assert_eq!(install[1].url, "/en/docs/installation.html");
+    assert!(install[1].current);
+
+    let templates = &articles[0].posts[1].translations;
+    assert_eq!(templates[1].url, "/en/docs/templates.html");
+    assert!(!templates[1].fallback);
+}
+
+#[test]
+fn missing_translation_falls_back_to_the_section_page() {
+    let app = make_app();
+    let mut articles = vec![
+        make_article("th", "บทความ", "seo", vec![make_post("only-thai", None)]),
+        make_article("en", "SEO", "seo", Vec::new()),
+        make_article("th", "เอกสาร", "docs", vec![make_post("no-section", None)]),
+    ];
+    link_translations(&mut articles, &app);
+
+    let english = &articles[0].posts[0].translations[1];
+    assert!(english.fallback);
+    assert_eq!(english.url, "/en/seo/");
+    assert_eq!(english.title, "SEO");
+
+    // No English docs section at all: the English home page
+    let english = &articles[2].posts[0].translations[1];
+    assert!(english.fallback);
+    assert_eq!(english.url, "/en/");
+
+    assert!(alternates(&articles[0].posts[0], &app).is_empty());
+}
+
+#[test]
+fn alternates_list_every_version_and_x_default() {
+    let app = make_app();
+    let mut articles = vec![
+        make_article("th", "เอกสาร", "docs", vec![make_post("a", Some("k"))]),
+        make_article("en", "Docs", "docs", vec![make_post("b", Some("k"))]),
+    ];
+    link_translations(&mut articles, &app);
+
+    let links: Vec<(String, String)> = alternates(&articles[1].posts[0], &app)
+        .into_iter()
+        .map(|a| (a.hreflang, a.href))
+        .collect();
+    assert_eq!(
+        links,
+        vec![
+            (
+                "th".to_string(),
+                "https://example.com/docs/a.html".to_string()
+            ),
+            (
+                "en".to_string(),
+                "https://example.com/en/docs/b.html".to_string()
+            ),
+            (
+                "x-default".to_string(),
+                "https://example.com/docs/a.html".to_string()
+            ),
+        ]
+    );
+}
@@ -1,6 +1,8 @@
 use crate::shared::validation::*;
 use regex::Regex;
+use std::collections::HashSet;
 use std::path::Path;
+use tempfile::TempDir;
 
 #[test]
 fn test_valid_markdown_filenames() {
@@ -163,3 +165,174 @@ fn test_validation_summary() {
     assert_eq!(summary.files_with_warnings, 1);
     assert!(summary.has_errors());
 }
+
+fn post_with(dir: &Path, body: &str) -> std::path::PathBuf {
+    let path = dir.join("2025-07-03-guide.md");
+    std::fs::write(&path, format!("---\ntitle: Guide\n---\n{body}")).unwrap();
+    path
+}
+
+/// `(line, message)` of each issue
+fn located(issues: &[Issue]) -> Vec<(Option<usize>, String)> {
+    issues.iter().map(|i| (i.line, i.message.clone())).collect()
+}
+
+#[test]
+fn validation_section_maps_onto_the_config() {
+    let section: crate::app::Validation = toml::from_str(
+        r#"
+        enabled = false
+        strict = true
+        allowed_image_formats = ["WEBP"]
+        forbidden_chars = ["~"]
+        max_image_size_kb = 512
+        max_image_width = 1600
+        max_image_height = 1200
+        banned_words = ["lorem"]
+        max_slug_length = 40
+
+        [severity]
+        alt_text = "error"
+        slug_length = "off"
+        "#,
+    )
+    .unwrap();
+    let app = crate::app::AppConfig {
+        validation: Some(section),
+        ..Default::default()
+    };
+
+    let config = ValidationConfig::from_app(&app);
+
+    assert!(!config.enabled);
+    assert!(config.strict);
+    assert_eq!(
+        config.allowed_image_formats,
+        HashSet::from(["webp".to_string()])
+    );
+    assert_eq!(config.forbidden_chars, vec!['~']);
+    assert_eq!(
+        (
+            config.max_image_size_kb,
+            config.max_image_width,
+            config.max_image_height
+        ),
+        (512, 1600, 1200)
+    );
+    assert_eq!(config.banned_words, vec!["lorem".to_string()]);
+    assert_eq!(config.max_slug_length, 40);
+    assert_eq!(config.severity.alt_text, RuleSeverity::Error);
+    assert_eq!(config.severity.slug_length, RuleSeverity::Off);
+    // Rules left out keep their default severity
+    assert_eq!(config.severity.filename_format, RuleSeverity::Error);
+
+    // A disabled validator reports nothing, even for a broken filename
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("Bad Name.md");
+    std::fs::write(&path, "---\ntitle: lorem\n---\n").unwrap();
+    let result = FileValidator::new(config).validate_file(&path).unwrap();
+    assert!(result.is_valid && !result.has_warnings());
+}
+
+#[test]
+fn images_are_checked_for_format_size_dimensions_and_alt_text() {
+    let temp = TempDir::new().unwrap();
+    let public = temp.path().join("public");
+    std::fs::create_dir_all(public.join("images")).unwrap();
+    image::RgbImage::new(40, 20)
+        .save(public.join("images/wide.png"))
+        .unwrap();
+    std::fs::write(public.join("images/big.webp"), vec![0u8; 3 * 1024]).unwrap();
+    let path = post_with(
+        temp.path(),
+        "![Wide](/images/wide.png)\n![](/images/big.webp)\n<img src=\"/images/a.gif\" alt=\"A\">\n\
+         ```\n![](ignored.bmp)\n```\n",
+    );
+    let validator = FileValidator::new(ValidationConfig {
+        max_image_size_kb: 2,
+        max_image_width: 30,
+        public_dir: public,
+        ..ValidationConfig::default()
+    });
+
+    let result = validator.validate_file(&path).unwrap();
+
+    assert!(result.is_valid);
+    assert_eq!(
+        located(&result.warnings),
+        vec![
+            (
+                Some(4),
+                "Image \"/images/wide.png\" is 40x20 pixels, larger than 30x2560".to_string()
+            ),
+            (Some(5), "Image \"/images/big.webp\" has no alt text".to_string()),
+            (
+                Some(5),
+                "Image \"/images/big.webp\" is 3 KB, larger than 2 KB".to_string()
+            ),
+            (
+                Some(6),
+                "Image \"/images/a.gif\" is not an allowed format (avif, jpeg, jpg, png, svg, webp)"
+                    .to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn banned_words_match_whole_words_at_their_severity() {
+    let temp = TempDir::new().unwrap();
+    let path = post_with(
+        temp.path(),
+        "Lorem Ipsum dolor\nPaeonia is a flower\nAEO matters\n",
+    );
+    let validator = FileValidator::new(ValidationConfig {
+        banned_words: vec!["lorem ipsum".to_string(), "aeo".to_string()],
+        ..ValidationConfig::default()
+    });
+
+    let result = validator.validate_file(&path).unwrap();
+
+    assert_eq!(
+        located(&result.errors),
+        vec![
+            (Some(4), "Banned word \"lorem ipsum\"".to_string()),
+            (Some(6), "Banned word \"aeo\"".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn severity_off_and_warn_change_how_a_rule_reports() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("Bad_Name.md");
+    std::fs::write(&path, "---\ntitle: A\n---\n![](a.webp)\n").unwrap();
+
+    let mut config = ValidationConfig::default();
+    config.severity.filename_format = RuleSeverity::Warn;
+    config.severity.alt_text = RuleSeverity::Off;
+    let result = FileValidator::new(config).validate_file(&path).unwrap();
+
+    assert!(result.is_valid);
+    assert_eq!(result.warnings.len(), 1);
+    assert!(result.warnings[0]
+        .message
+        .starts_with("Invalid markdown filename format: 'Bad_Name.md'"));
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_filename_switch_still_turns_the_check_off() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("Bad_Name.md");
+    std::fs::write(&path, "---\ntitle: A\n---\n").unwrap();
+
+    let config = ValidationConfig {
+        check_markdown_filename_format: false,
+        ..ValidationConfig::default()
+    };
+    let result = FileValidator::new(config).validate_file(&path).unwrap();
+
+    assert!(result.is_valid);
+    assert!(result.warnings.is_empty());
+}
@@ -0,0 +1,79 @@
+use crate::shared::word_count::{segment_thai, text_stats, ThaiToken};
+
+#[test]
+fn thai_is_segmented_with_the_dictionary() {
+    assert_eq!(
+        segment_thai("การพัฒนาเว็บไซต์ด้วยภาษาไทย"),
+        vec![
+            ThaiToken::Word("การพัฒนา"),
+            ThaiToken::Word("เว็บไซต์"),
+            ThaiToken::Word("ด้วย"),
+            ThaiToken::Word("ภาษาไทย"),
+        ]
+    );
+    assert_eq!(text_stats("การพัฒนาเว็บไซต์ด้วยภาษาไทย").word_count, 4);
+}
+
+#[test]
+fn unknown_thai_is_kept_together_and_estimated() {
+    // "ซุปเปอร์" is not in the list; it stays one unknown piece between known words
+    let tokens = segment_thai("ใช้ซุปเปอร์ได้");
+    assert_eq!(
+        tokens,
+        vec![
+            ThaiToken::Word("ใช้"),
+            ThaiToken::Unknown("ซุปเปอร์"),
+            ThaiToken::Word("ได้"),
+        ]
+    );
+    assert_eq!(text_stats("ใช้ซุปเปอร์ได้").word_count, 4);
+}
+
+#[test]
+fn latin_and_mixed_text() {
+    let stats = text_stats("Don't repeat yourself: 3 rules, well-known.");
+    assert_eq!(stats.word_count, 6);
+    assert_eq!(stats.char_count, 38);
+
+    assert_eq!(text_stats("ติดตั้ง GenWebBlog บนเครื่อง").word_count, 4);
+    assert_eq!(text_stats("").word_count, 0);
+}
+
+#[test]
+fn reading_speed_per_language() {
+    let app = crate::app::AppConfig {
+        reading: Some(crate::app::Reading {
+            words_per_minute: Some([("en".to_string(), 230)].into_iter().collect()),
+        }),
+        ..Default::default()
+    };
+    assert_eq!(app.reading_speed("en"), 230);
+    assert_eq!(
+        app.reading_speed("th"),
+        crate::constants::content::READING_TIME_WPM
+    );
+}
+
+#[test]
+fn post_reading_time_uses_the_given_speed() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let path = temp.path().join("2025-01-05-long-read.md");
+    let body = "word ".repeat(300);
+    std::fs::write(&path, format!("---\ntitle: Long read\n---\n\n{body}\n")).unwrap();
+    let app = crate::app::AppConfig::default();
+    let open = |words_per_minute| {
+        crate::posts::Post::open(
+            &path,
+            &app,
+            std::path::Path::new("blog"),
+            "/{section}/{slug}.html",
+            "",
+            &crate::shared::markdown_to_html::options::options(&app, None),
+            words_per_minute,
+        )
+        .unwrap()
+    };
+
+    assert_eq!(open(100).reading_time, 3);
+    assert_eq!(open(300).reading_time, 1);
+}